@@ -0,0 +1,23 @@
+//! Feeds arbitrary input to a day, picked by the first byte, and solves both parts. Days must
+//! report malformed or unsolvable input as an error instead of panicking. Run with
+//! `cargo +nightly fuzz run parse -- -timeout=10`, so that inputs a day takes too long on are
+//! reported too.
+//!
+//! Days 4, 10 and 11 are only parsed, as some inputs take them millions of steps. Inputs of more
+//! than 4 lines are only parsed as well, since days 9, 13 and 15 try every arrangement of them.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use advent_of_code::solution::Part;
+use advent_of_code::year2015::DAYS;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((day, input)) = data.split_first() else {
+        return;
+    };
+    if let Ok(input) = std::str::from_utf8(input) {
+        let day = &DAYS[*day as usize % DAYS.len()];
+        let solve = ![4, 10, 11].contains(&day.day) && input.lines().count() <= 4;
+        let _ = (day.solve)(input, if solve { &Part::ALL[..] } else { &[][..] });
+    }
+});
@@ -0,0 +1,9 @@
+//! Unescapes arbitrary string literals of day 8. Run with `cargo +nightly fuzz run unescape`.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use advent_of_code::year2015::day8::unescape;
+
+fuzz_target!(|literal: &str| {
+    let _ = unescape(literal);
+});
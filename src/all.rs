@@ -0,0 +1,110 @@
+use std::path::Path;
+use std::time::{Duration, Instant};
+use itertools::Itertools;
+use rayon::prelude::*;
+
+use crate::error::Result;
+use crate::solution::{Part, Run, Year};
+use crate::util::InputSource;
+
+enum Outcome {
+    Solved(Run),
+    NoInput,
+    NotImplemented,
+    Failed,
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1000.0)
+}
+
+/// The days among `totals` that took longest, at most `n` of them.
+fn slowest(totals: &[(u32, Duration)], n: usize) -> Vec<u32> {
+    totals.iter()
+        .sorted_by(|a, b| b.1.cmp(&a.1))
+        .take(n)
+        .map(|(day, _)| *day)
+        .collect()
+}
+
+/// Runs the given days concurrently and prints one table with their answers and timings.
+/// Returns `false` if any day could not be solved.
+pub fn all(solutions: &Year, days: &[u32], highlight: usize) -> Result<bool> {
+    let start = Instant::now();
+    let outcomes = days.par_iter()
+        .map(|day| {
+            let Some(solution) = solutions.day(*day) else {
+                return (*day, Outcome::NotImplemented);
+            };
+            let source = InputSource::for_day(solutions.year, *day);
+            if !Path::new(&source.to_string()).exists() {
+                return (*day, Outcome::NoInput);
+            }
+            let outcome = source.read()
+                .and_then(|input| (solution.solve)(&input, &Part::ALL))
+                .map_err(|e| e.in_file(&source.to_string()));
+            match outcome {
+                Ok(run) => (*day, Outcome::Solved(run)),
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    (*day, Outcome::Failed)
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    let wall_time = start.elapsed();
+
+    let totals = outcomes.iter()
+        .filter_map(|(day, outcome)| match outcome {
+            Outcome::Solved(run) => Some((*day, run.total())),
+            _ => None,
+        })
+        .collect_vec();
+    let slowest = slowest(&totals, highlight);
+    let answer_width = outcomes.iter()
+        .flat_map(|(_, outcome)| match outcome {
+            Outcome::Solved(run) => run.parts.iter().map(|p| p.answer.to_string().len()).collect_vec(),
+            _ => vec![],
+        })
+        .chain(["not implemented".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!("{:>3}  {:>10}  {:<w$}  {:>10}  {:<w$}  {:>10}  {:>10}",
+             "Day", "Parse (ms)", "Part 1", "Time (ms)", "Part 2", "Time (ms)", "Total (ms)", w = answer_width);
+    for (day, outcome) in &outcomes {
+        match outcome {
+            Outcome::Solved(run) => {
+                let cells = run.parts.iter()
+                    .map(|p| format!("{:<w$}  {:>10}", p.answer, millis(p.duration), w = answer_width))
+                    .join("  ");
+                let marker = if slowest.contains(day) { "  <- slow" } else { "" };
+                println!("{:>3}  {:>10}  {}  {:>10}{}", day, millis(run.parse), cells, millis(run.total()), marker);
+            }
+            Outcome::NoInput => println!("{:>3}  {:>10}  no input", day, ""),
+            Outcome::NotImplemented => println!("{:>3}  {:>10}  not implemented", day, ""),
+            Outcome::Failed => println!("{:>3}  {:>10}  ERROR", day, ""),
+        }
+    }
+
+    let sum = totals.iter().map(|(_, d)| *d).sum::<Duration>();
+    println!();
+    println!("Solved {} day(s) in {} ms wall time ({} ms of solving across {} threads)",
+             totals.len(), millis(wall_time), millis(sum), rayon::current_num_threads());
+    Ok(outcomes.iter().all(|(_, outcome)| !matches!(outcome, Outcome::Failed)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::all::slowest;
+
+    #[test]
+    fn picks_slowest_days() {
+        let totals = [(1, Duration::from_millis(5)), (2, Duration::from_millis(50)), (3, Duration::from_millis(20))];
+
+        assert_eq!(slowest(&totals, 2), vec![2, 3]);
+        assert_eq!(slowest(&totals, 5), vec![2, 3, 1]);
+        assert_eq!(slowest(&totals, 0), Vec::<u32>::new());
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use itertools::Itertools;
+
+use crate::error::{Error, Result};
+use crate::solution::{Part, Year};
+use crate::util;
+
+pub fn baseline_file(year: u32) -> String {
+    format!("bench_baseline_{}.toml", year)
+}
+
+/// Mean phase timings in microseconds, keyed by `dayN` (or `dayN-sizeS-seedS` for generated inputs) and then by phase.
+type Baseline = BTreeMap<String, BTreeMap<String, f64>>;
+
+pub struct Options {
+    pub warmup: usize,
+    pub runs: usize,
+    pub baseline_file: String,
+    pub save_baseline: bool,
+    pub threshold: f64,
+    /// Size and seed of generated inputs to use instead of the puzzle inputs
+    pub generated: Option<(usize, u64)>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Stats {
+    mean: f64,
+    median: f64,
+    stddev: f64,
+}
+
+impl Stats {
+    fn from(samples: &[Duration]) -> Stats {
+        let micros = samples.iter()
+            .map(|d| d.as_secs_f64() * 1_000_000.0)
+            .sorted_by(f64::total_cmp)
+            .collect_vec();
+        let n = micros.len();
+        let mean = micros.iter().sum::<f64>() / n as f64;
+        let median = if n % 2 == 0 {
+            (micros[n / 2 - 1] + micros[n / 2]) / 2.0
+        } else {
+            micros[n / 2]
+        };
+        let variance = if n > 1 {
+            micros.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+        Stats { mean, median, stddev: variance.sqrt() }
+    }
+}
+
+fn phase_name(part: Part) -> String {
+    format!("part{}", part)
+}
+
+fn load_baseline(file_path: &str) -> Result<Baseline> {
+    if !Path::new(file_path).exists() {
+        return Ok(Baseline::new());
+    }
+    toml::from_str(&util::read_file(file_path)?)
+        .map_err(|e| Error::parse("", e.message()).in_file(file_path))
+}
+
+/// Runs every given day repeatedly and prints timing statistics per phase.
+/// Returns `false` if a phase got slower than the baseline by more than the threshold.
+pub fn bench(solutions: &Year, days: &[u32], options: &Options) -> Result<bool> {
+    let mut baseline = load_baseline(&options.baseline_file)?;
+    let mut regressions = 0;
+
+    println!(
+        "{:>3}  {:<6}  {:>12}  {:>12}  {:>12}  {:>12}  Change",
+        "Day", "Phase", "Mean (µs)", "Median (µs)", "Stddev (µs)", "Base (µs)"
+    );
+    for day in days {
+        let Some(solution) = solutions.day(*day) else {
+            continue;
+        };
+        let (input_file, input, key) = match options.generated {
+            Some((size, seed)) => {
+                let input = match (solution.generate)(size, seed) {
+                    Ok(Some(input)) => input,
+                    Ok(None) => {
+                        println!("{:>3}  skipped, there is no input generator", day);
+                        continue;
+                    }
+                    Err(e) => {
+                        println!("{:>3}  skipped, {}", day, e);
+                        continue;
+                    }
+                };
+                (format!("<generated, size {}, seed {}>", size, seed), input, format!("day{}-size{}-seed{}", day, size, seed))
+            }
+            None => {
+                let input_file = util::input_file(solutions.year, *day);
+                if !Path::new(&input_file).exists() {
+                    println!("{:>3}  skipped, {} does not exist", day, input_file);
+                    continue;
+                }
+                let input = util::read_file(&input_file)?;
+                (input_file, input, format!("day{}", day))
+            }
+        };
+        let solve = || (solution.solve)(&input, &Part::ALL).map_err(|e| e.in_file(&input_file));
+
+        for _ in 0..options.warmup {
+            solve()?;
+        }
+        let mut samples: BTreeMap<String, Vec<Duration>> = BTreeMap::new();
+        for _ in 0..options.runs {
+            let run = solve()?;
+            samples.entry("parse".to_string()).or_default().push(run.parse);
+            for result in run.parts {
+                samples.entry(phase_name(result.part)).or_default().push(result.duration);
+            }
+        }
+
+        let mut means = BTreeMap::new();
+        for (phase, samples) in samples {
+            let stats = Stats::from(&samples);
+            let base = baseline.get(&key).and_then(|phases| phases.get(&phase)).copied();
+            let change = match base {
+                Some(base) if base > 0.0 => {
+                    let percent = (stats.mean - base) / base * 100.0;
+                    let flag = if percent > options.threshold {
+                        regressions += 1;
+                        "  REGRESSION"
+                    } else {
+                        ""
+                    };
+                    format!("{:+.1}%{}", percent, flag)
+                }
+                _ => String::new(),
+            };
+            let base = base.map(|b| format!("{:.1}", b)).unwrap_or_default();
+            println!(
+                "{:>3}  {:<6}  {:>12.1}  {:>12.1}  {:>12.1}  {:>12}  {}",
+                day, phase, stats.mean, stats.median, stats.stddev, base, change
+            );
+            means.insert(phase, (stats.mean * 10.0).round() / 10.0);
+        }
+        if options.save_baseline {
+            baseline.insert(key, means);
+        }
+    }
+
+    if options.save_baseline {
+        let contents = toml::to_string(&baseline).expect("Failed to serialize baseline");
+        fs::write(&options.baseline_file, contents)
+            .map_err(|source| Error::Io { path: options.baseline_file.clone(), source })?;
+        println!();
+        println!("Saved baseline to {}", options.baseline_file);
+    }
+    if regressions > 0 {
+        println!();
+        println!("{} phase(s) slower than the baseline by more than {}%", regressions, options.threshold);
+    }
+    Ok(regressions == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::bench::Stats;
+
+    #[test]
+    fn computes_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_micros);
+
+        let stats = Stats::from(&samples);
+
+        assert_eq!(stats.mean, 2.5);
+        assert_eq!(stats.median, 2.5);
+        assert!((stats.stddev - 1.29099).abs() < 1e-5);
+    }
+
+    #[test]
+    fn computes_stats_of_single_sample() {
+        let stats = Stats::from(&[Duration::from_micros(7)]);
+
+        assert_eq!(stats, Stats { mean: 7.0, median: 7.0, stddev: 0.0 });
+    }
+}
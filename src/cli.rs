@@ -0,0 +1,236 @@
+use std::str::FromStr;
+use clap::{Parser, Subcommand, ValueEnum};
+use clap::builder::RangedU64ValueParser;
+use itertools::Itertools;
+use advent_of_code::year2015::day7;
+
+use crate::solution::Part;
+use crate::util::InputSource;
+
+#[derive(Parser, Debug)]
+#[command(about = "Solutions for Advent of Code")]
+pub struct Cli {
+    /// Year of the event, defaults to the latest year with solutions
+    #[arg(short, long, global = true)]
+    pub year: Option<u32>,
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Run the solutions of one or more days
+    Run {
+        /// Days to run, e.g. `7`, `1-5`, `1,3,10-12` or `all`
+        days: Days,
+        /// Only run this part (1 or 2)
+        #[arg(short, long)]
+        part: Option<Part>,
+        /// Read the puzzle input from this file instead of `resources/YEAR/dayN.txt`, or `-` for stdin
+        #[arg(short, long)]
+        input: Option<InputSource>,
+        /// Print how long each day took
+        #[arg(short, long)]
+        time: bool,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Run days in parallel and print a summary table of their answers and timings
+    All {
+        /// Days to run
+        #[arg(default_value = "all")]
+        days: Days,
+        /// Number of slowest days to highlight
+        #[arg(short = 'n', long, default_value_t = 3)]
+        slowest: usize,
+    },
+    /// Check the solutions against the known answers
+    Verify {
+        /// Days to verify
+        #[arg(default_value = "all")]
+        days: Days,
+        /// File with the known answers, defaults to `resources/YEAR/answers.toml`
+        #[arg(short, long)]
+        answers: Option<String>,
+    },
+    /// Check the solutions against the examples from the puzzle statements
+    Examples {
+        /// Days to check
+        #[arg(default_value = "all")]
+        days: Days,
+        /// File with the examples, defaults to `resources/YEAR/examples.toml`
+        #[arg(short, long)]
+        examples: Option<String>,
+    },
+    /// Time each phase of the solutions over repeated runs
+    Bench {
+        /// Days to benchmark
+        #[arg(default_value = "all")]
+        days: Days,
+        /// Number of untimed runs before measuring
+        #[arg(short, long, default_value_t = 3)]
+        warmup: usize,
+        /// Number of timed runs
+        #[arg(short, long, default_value_t = 10, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+        runs: usize,
+        /// File with the baseline timings to compare against, defaults to `bench_baseline_YEAR.toml`
+        #[arg(short, long)]
+        baseline: Option<String>,
+        /// Store the measured timings as the new baseline
+        #[arg(short, long)]
+        save_baseline: bool,
+        /// Slowdown in percent that counts as a regression
+        #[arg(short, long, default_value_t = 10.0)]
+        threshold: f64,
+        /// Benchmark on generated inputs of this size instead of the puzzle inputs
+        #[arg(long)]
+        size: Option<usize>,
+        /// Seed for the generated inputs
+        #[arg(long, default_value_t = 0, requires = "size")]
+        seed: u64,
+    },
+    /// Print a random input for a day, e.g. to pipe into `run DAY --input -`
+    Generate {
+        /// Day to generate an input for
+        #[arg(value_parser = parse_day)]
+        day: u32,
+        /// Size of the input, what it counts depends on the day
+        #[arg(short, long, default_value_t = 100)]
+        size: usize,
+        /// Seed for the random generator, the same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Re-run a day whenever its source or input changes
+    Watch {
+        /// Day to watch
+        #[arg(value_parser = parse_day)]
+        day: u32,
+        /// Build in release mode
+        #[arg(short, long)]
+        release: bool,
+    },
+    /// Create a module for a new day from a template and register it
+    New {
+        /// Day to create
+        #[arg(value_parser = parse_day)]
+        day: u32,
+    },
+    /// Print the graph in a day's input, for days 7 and 9
+    Export {
+        /// Day to export
+        #[arg(value_parser = parse_day)]
+        day: u32,
+        /// Output format of the graph
+        #[arg(short, long, value_enum, default_value_t = GraphFormat::Dot)]
+        format: GraphFormat,
+        /// Read the puzzle input from this file instead of `resources/YEAR/dayN.txt`, or `-` for stdin
+        #[arg(short, long)]
+        input: Option<InputSource>,
+    },
+    /// Compile the day 7 circuit to a straight-line function in Rust or C
+    Compile {
+        /// Language of the generated code
+        #[arg(short, long, value_enum, default_value_t = day7::Language::Rust)]
+        language: day7::Language,
+        /// Number of bits on each wire: 8, 16, 32 or 64
+        #[arg(short, long, default_value_t = 16, value_parser = parse_bits)]
+        bits: u32,
+        /// Read the circuit from this file instead of `resources/2015/day7.txt`, or `-` for stdin
+        #[arg(short, long)]
+        input: Option<InputSource>,
+    },
+    /// Download puzzle inputs into `resources/YEAR/`, using the session token in `.env`
+    Fetch {
+        /// Days to download
+        days: Days,
+        /// Overwrite inputs that were already downloaded
+        #[arg(short, long)]
+        force: bool,
+    },
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    /// One line per answer
+    Text,
+    /// A JSON array with one record per answer
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum GraphFormat {
+    /// Graphviz DOT, e.g. to pipe into `dot -Tsvg`
+    Dot,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Days(pub Vec<u32>);
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Days((1..=25).collect()));
+        }
+        let mut days = vec![];
+        for item in s.split(',') {
+            match item.split_once('-') {
+                Some((from, to)) => {
+                    let (from, to) = (parse_day(from)?, parse_day(to)?);
+                    if from > to {
+                        return Err(format!("`{}` is not an ascending range", item));
+                    }
+                    days.extend(from..=to);
+                }
+                None => days.push(parse_day(item)?),
+            }
+        }
+        Ok(Days(days.into_iter().unique().collect()))
+    }
+}
+
+fn parse_day(s: &str) -> Result<u32, String> {
+    match s.trim().parse() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("`{}` is not a day between 1 and 25", s)),
+    }
+}
+
+fn parse_bits(s: &str) -> Result<u32, String> {
+    match s.trim().parse() {
+        Ok(bits @ (8 | 16 | 32 | 64)) => Ok(bits),
+        _ => Err(format!("`{}` is not one of 8, 16, 32 or 64", s)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use advent_of_code::year2015::day7::Language;
+    use crate::cli::{Cli, Command, Days};
+
+    #[test]
+    fn parses_days() {
+        assert_eq!("7".parse(), Ok(Days(vec![7])));
+        assert_eq!("1-3,10,2".parse(), Ok(Days(vec![1, 2, 3, 10])));
+        assert_eq!("all".parse::<Days>().unwrap().0.len(), 25);
+        assert!("0".parse::<Days>().is_err());
+        assert!("5-3".parse::<Days>().is_err());
+        assert!("x".parse::<Days>().is_err());
+    }
+
+    #[test]
+    fn parses_compile_languages() {
+        let language = |args: &[&str]| match Cli::try_parse_from([&["aoc", "compile"], args].concat()).map(|cli| cli.command) {
+            Ok(Command::Compile { language, .. }) => Some(language),
+            _ => None,
+        };
+
+        assert_eq!(language(&[]), Some(Language::Rust));
+        assert_eq!(language(&["--language", "c"]), Some(Language::C));
+        assert_eq!(language(&["-l", "java"]), None);
+    }
+}
@@ -0,0 +1,27 @@
+use std::str::FromStr;
+use advent_of_code::year2015::day7::{Circuit, Language, Word};
+
+use crate::error::Result;
+use crate::util::{write_stdout, InputSource};
+
+fn compile_as<W: Word>(input: &str, language: Language) -> Result<String> {
+    Ok(Circuit::<W>::from_str(input)?.compile(language))
+}
+
+/// Prints the circuit in `source` as a function in `language` over words of `bits` bits.
+/// Returns `false` if the year has no circuit to compile.
+pub fn compile(year: u32, language: Language, bits: u32, source: &InputSource) -> Result<bool> {
+    if year != 2015 {
+        eprintln!("{} has no circuit to compile, only day 7 of 2015 does", year);
+        return Ok(false);
+    }
+    let input = source.read()?;
+    let code = match bits {
+        8 => compile_as::<u8>(&input, language),
+        16 => compile_as::<u16>(&input, language),
+        32 => compile_as::<u32>(&input, language),
+        _ => compile_as::<u64>(&input, language),
+    };
+    write_stdout(code.map_err(|e| e.in_file(&source.to_string()))?)?;
+    Ok(true)
+}
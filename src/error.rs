@@ -0,0 +1,195 @@
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::ops::{Bound, RangeBounds};
+use std::num::ParseIntError;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        path: String,
+        source: io::Error,
+    },
+    Parse {
+        file: Option<String>,
+        line: Option<usize>,
+        text: String,
+        message: String,
+    },
+    Fetch {
+        url: String,
+        message: String,
+    },
+    /// A lookup by name, such as of a wire in a circuit, found nothing
+    NotFound {
+        kind: &'static str,
+        name: String,
+    },
+    /// An input that parsed but has no answer, such as a program that never halts
+    Unsolvable {
+        message: String,
+    },
+    /// A generator was asked for an input of a size the puzzle's format cannot have
+    Size {
+        size: usize,
+        min: usize,
+        max: Option<usize>,
+    },
+}
+
+impl Error {
+    pub fn parse(text: &str, message: impl Display) -> Error {
+        Error::Parse {
+            file: None,
+            line: None,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn unsolvable(message: impl Display) -> Error {
+        Error::Unsolvable { message: message.to_string() }
+    }
+
+    /// Checks that a generator can honour `size`, rather than quietly generating an input of
+    /// another size.
+    pub fn check_size(size: usize, sizes: impl RangeBounds<usize>) -> Result<usize> {
+        if sizes.contains(&size) {
+            return Ok(size);
+        }
+        let min = match sizes.start_bound() {
+            Bound::Included(&min) => min,
+            Bound::Excluded(&min) => min + 1,
+            Bound::Unbounded => 0,
+        };
+        let max = match sizes.end_bound() {
+            Bound::Included(&max) => Some(max),
+            Bound::Excluded(&max) => Some(max - 1),
+            Bound::Unbounded => None,
+        };
+        Err(Error::Size { size, min, max })
+    }
+
+    /// Checks a setting from an examples file, which must be at least `min` and fit in the type
+    /// the puzzle uses.
+    pub fn check_setting<T: TryFrom<i64>>(name: &str, value: i64, min: i64) -> Result<T> {
+        match T::try_from(value) {
+            Ok(setting) if value >= min => Ok(setting),
+            _ => Err(Error::parse(
+                &value.to_string(),
+                format!("expected a {} setting of at least {} that fits in a {}", name, min, std::any::type_name::<T>()),
+            )),
+        }
+    }
+
+    /// Rejects a setting the puzzle does not have.
+    pub fn unknown_setting(name: &str) -> Error {
+        Error::parse(name, "the puzzle has no such setting")
+    }
+
+    /// Records the (1-based) line the error occurred on, and the line itself if the
+    /// error does not carry more specific text yet.
+    pub fn at_line(self, number: usize, line: &str) -> Error {
+        match self {
+            Error::Parse { file, line: None, text, message } => Error::Parse {
+                file,
+                line: Some(number),
+                text: if text.is_empty() { line.to_string() } else { text },
+                message,
+            },
+            other => other,
+        }
+    }
+
+    pub fn in_file(self, path: &str) -> Error {
+        match self {
+            Error::Parse { file: None, line, text, message } => Error::Parse {
+                file: Some(path.to_string()),
+                line,
+                text,
+                message,
+            },
+            other => other,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{}: {}", path, source),
+            Error::Parse { file, line, text, message } => {
+                match (file, line) {
+                    (Some(file), Some(line)) => write!(f, "{}:{}: ", file, line)?,
+                    (Some(file), None) => write!(f, "{}: ", file)?,
+                    (None, Some(line)) => write!(f, "line {}: ", line)?,
+                    (None, None) => {}
+                }
+                write!(f, "{}", message)?;
+                if !text.is_empty() {
+                    write!(f, "\n    | {}", text)?;
+                }
+                Ok(())
+            }
+            Error::Fetch { url, message } => write!(f, "could not fetch {}: {}", url, message),
+            Error::NotFound { kind, name } => write!(f, "unknown {} `{}`", kind, name),
+            Error::Unsolvable { message } => write!(f, "no answer: {}", message),
+            Error::Size { size, min, max: Some(max) } => {
+                write!(f, "cannot generate an input of size {}, expected between {} and {}", size, min, max)
+            }
+            Error::Size { size, min, max: None } => {
+                write!(f, "cannot generate an input of size {}, expected at least {}", size, min)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseIntError> for Error {
+    fn from(e: ParseIntError) -> Self {
+        Error::parse("", e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+
+    #[test]
+    fn formats_parse_errors() {
+        let error = Error::parse("", "expected a number")
+            .at_line(3, "2xfoo")
+            .in_file("resources/day2.txt");
+
+        assert_eq!(error.to_string(), "resources/day2.txt:3: expected a number\n    | 2xfoo");
+    }
+
+    #[test]
+    fn keeps_the_most_specific_text() {
+        let error = Error::parse("foo", "unknown token").at_line(1, "1 foo 2");
+
+        assert_eq!(error.to_string(), "line 1: unknown token\n    | foo");
+    }
+
+    #[test]
+    fn reports_sizes_out_of_range() {
+        assert_eq!(Error::check_size(12, 12..=1440).unwrap(), 12);
+        assert_eq!(Error::check_size(1, 2..).unwrap_err().to_string(), "cannot generate an input of size 1, expected at least 2");
+        assert_eq!(
+            Error::check_size(71, 1..=70).unwrap_err().to_string(),
+            "cannot generate an input of size 71, expected between 1 and 70",
+        );
+    }
+
+    #[test]
+    fn reports_settings_out_of_range() {
+        assert_eq!(Error::check_setting::<u32>("seconds", 1000, 0).unwrap(), 1000);
+        assert_eq!(
+            Error::check_setting::<u32>("seconds", -1, 0).unwrap_err().to_string(),
+            "expected a seconds setting of at least 0 that fits in a u32\n    | -1",
+        );
+        assert!(Error::check_setting::<i32>("mana", 1 << 40, 0).is_err());
+    }
+}
@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+
+use crate::error::{Error, Result};
+use crate::solution::{Part, Settings, Year};
+use crate::util;
+use crate::verify::{check, parse_day_key, Expected, Status};
+
+pub fn examples_file(year: u32) -> String {
+    format!("{}/examples.toml", util::resources(year))
+}
+
+#[derive(Deserialize, Debug)]
+struct Example {
+    input: String,
+    /// Numbers the puzzle fixes that the example changes, such as how long a race lasts.
+    #[serde(default)]
+    settings: Settings,
+    #[serde(flatten)]
+    expected: Expected,
+}
+
+fn parse_examples(contents: &str) -> Result<HashMap<u32, Vec<Example>>> {
+    let examples: HashMap<String, Vec<Example>> = toml::from_str(contents)
+        .map_err(|e| Error::parse("", e.message()))?;
+    examples.into_iter()
+        .map(|(key, examples)| Ok((parse_day_key(&key)?, examples)))
+        .collect()
+}
+
+/// Runs the given days on their examples and prints a table comparing them to the expected answers.
+/// Returns `false` if any answer is wrong.
+pub fn examples(solutions: &Year, days: &[u32], examples_file: &str) -> Result<bool> {
+    let examples = parse_examples(&util::read_file(examples_file)?).map_err(|e| e.in_file(examples_file))?;
+    let mut total = 0;
+    let mut failures = 0;
+
+    println!("{:>3}  {:>7}  {:>4}  {:<7}  {:<16}  Expected", "Day", "Example", "Part", "Status", "Answer");
+    for day in days {
+        let Some(solution) = solutions.day(*day) else {
+            continue;
+        };
+        for (i, example) in examples.get(day).into_iter().flatten().enumerate() {
+            let parts = Part::ALL.into_iter().filter(|p| example.expected.get(*p).is_some()).collect::<Vec<_>>();
+            let actual = (solution.solve_with)(&example.input, &example.settings, &parts)
+                .map_err(|e| eprintln!("error: day {} example {}: {}", day, i + 1, e));
+            for part in parts {
+                let expected = example.expected.get(part);
+                let (status, answer) = match actual.as_ref().map(|run| run.parts.iter().find(|r| r.part == part)) {
+                    Ok(Some(result)) => (check(expected, &result.answer), result.answer.to_string()),
+                    _ => (Status::Error, String::new()),
+                };
+                total += 1;
+                if status != Status::Pass {
+                    failures += 1;
+                }
+                let expected = expected.map(|e| e.to_string()).unwrap_or_default();
+                println!("{:>3}  {:>7}  {:>4}  {:<7}  {:<16}  {}", day, i + 1, part, status.label(), answer, expected);
+            }
+        }
+    }
+
+    println!();
+    if failures > 0 {
+        println!("{} of {} example answer(s) are wrong or could not be computed", failures, total);
+    } else {
+        println!("All {} example answer(s) match", total);
+    }
+    Ok(failures == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{examples_file, parse_examples};
+    use crate::solution::{Answer, Part};
+    use crate::util;
+
+    #[test]
+    fn parses_examples() {
+        let examples = parse_examples("[[day2]]\ninput = \"2x3x4\"\npart1 = 58\n\n[[day2]]\ninput = \"1x1x10\"\npart2 = 14\n").unwrap();
+
+        assert_eq!(examples[&2].len(), 2);
+        assert_eq!(examples[&2][0].input, "2x3x4");
+        assert_eq!(examples[&2][0].expected.get(Part::One), Some(&Answer::Number(58)));
+        assert_eq!(examples[&2][1].expected.get(Part::One), None);
+        assert_eq!(examples[&2][1].expected.get(Part::Two), Some(&Answer::Number(14)));
+        assert!(examples[&2][0].settings.is_empty());
+    }
+
+    #[test]
+    fn parses_example_settings() {
+        let examples = parse_examples("[[day17]]\ninput = \"20\\n15\\n10\\n5\\n5\"\nsettings = { liters = 25 }\npart1 = 4\n").unwrap();
+
+        assert_eq!(examples[&17][0].settings["liters"], 25);
+        assert_eq!(examples[&17][0].expected.get(Part::One), Some(&Answer::Number(4)));
+    }
+
+    #[test]
+    fn parses_examples_file() {
+        let examples = parse_examples(&util::read_file(&examples_file(2015)).unwrap()).unwrap();
+
+        assert!(examples[&1].iter().all(|e| !e.input.is_empty()));
+    }
+}
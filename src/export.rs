@@ -0,0 +1,48 @@
+use petgraph::dot::Dot;
+use advent_of_code::year2015::{day7, day9};
+
+use crate::cli::GraphFormat;
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use crate::util::{write_stdout, InputSource};
+
+/// Prints the graph in the input of `day` in the given format.
+/// Returns `false` if the day has no graph to export.
+pub fn export(year: u32, day: u32, format: GraphFormat, source: &InputSource) -> Result<bool> {
+    let parse_error = |e: Error| e.in_file(&source.to_string());
+    match (year, day) {
+        (2015, 7) => {
+            // Any well-formed circuit, not only one with the wires `a` and `b` that the puzzle needs
+            let circuit: day7::Circuit = source.read()?.parse().map_err(parse_error)?;
+            match format {
+                GraphFormat::Dot => write_stdout(format_args!("{}\n", Dot::new(&circuit.graph())))?,
+            }
+        }
+        (2015, 9) => {
+            let connections = day9::Day9::parse(&source.read()?).map_err(parse_error)?;
+            match format {
+                GraphFormat::Dot => write_stdout(format_args!("{}\n", Dot::new(&day9::graph(&connections))))?,
+            }
+        }
+        _ => {
+            eprintln!("Day {} of {} has no graph to export, only days 7 and 9 of 2015 do", day, year);
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::GraphFormat;
+    use crate::export::export;
+    use crate::util::InputSource;
+
+    #[test]
+    fn exports_circuits_without_the_puzzle_wires() {
+        let source = InputSource::Inline("123 -> x\nNOT x -> y".to_string());
+
+        assert!(export(2015, 7, GraphFormat::Dot, &source).unwrap());
+        assert!(export(2015, 7, GraphFormat::Dot, &InputSource::Inline("x -> ".to_string())).is_err());
+    }
+}
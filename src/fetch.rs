@@ -0,0 +1,173 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_VARIABLE: &str = "AOC_SESSION";
+
+/// Minimum time between two requests to the server, to stay well within its rate limits.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), " input fetcher (", env!("CARGO_PKG_REPOSITORY"), ")");
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, min_interval: Duration) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            min_interval,
+            last_request: None,
+        }
+    }
+
+    pub fn fetch_input(&mut self, year: u32, day: u32) -> Result<String> {
+        if let Some(elapsed) = self.last_request.map(|t| t.elapsed()) {
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .call();
+        self.last_request = Some(Instant::now());
+        let message = match response {
+            Ok(response) => return response.into_string()
+                .map_err(|e| Error::Fetch { url, message: e.to_string() }),
+            Err(ureq::Error::Status(400 | 401 | 403, _)) => "the session token was rejected".to_string(),
+            Err(ureq::Error::Status(404, _)) => "the puzzle is not unlocked yet".to_string(),
+            Err(ureq::Error::Status(429, _)) => "rate limited by the server, try again later".to_string(),
+            Err(ureq::Error::Status(status, _)) => format!("the server answered with status {}", status),
+            Err(e) => e.to_string(),
+        };
+        Err(Error::Fetch { url, message })
+    }
+}
+
+/// Reads the session token from `.env` or the environment.
+pub fn session() -> Result<String> {
+    dotenv::dotenv().ok();
+    env::var(SESSION_VARIABLE)
+        .ok()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or_else(|| Error::Fetch {
+            url: BASE_URL.to_string(),
+            message: format!("{} is not set in .env or the environment", SESSION_VARIABLE),
+        })
+}
+
+/// Downloads the inputs of the given days that are not cached yet, or all of them when `force` is set.
+pub fn fetch(year: u32, days: &[u32], input_file: &dyn Fn(u32) -> String, force: bool, client: impl FnOnce() -> Result<Client>) -> Result<bool> {
+    let missing = days.iter()
+        .filter(|day| force || !Path::new(&input_file(**day)).exists())
+        .collect::<Vec<_>>();
+    for day in days.iter().filter(|day| !missing.contains(day)) {
+        println!("{:>3}  skipped, {} already exists", day, input_file(*day));
+    }
+    if missing.is_empty() {
+        return Ok(true);
+    }
+    let mut client = client()?;
+    for day in missing {
+        let path = input_file(*day);
+        let input = client.fetch_input(year, *day)?;
+        if let Some(parent) = Path::new(&path).parent() {
+            fs::create_dir_all(parent).map_err(|source| Error::Io { path: path.clone(), source })?;
+        }
+        fs::write(&path, input).map_err(|source| Error::Io { path: path.clone(), source })?;
+        println!("{:>3}  saved {}", day, path);
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::thread;
+    use std::time::{Duration, Instant};
+    use tiny_http::{Header, Response, Server};
+
+    use crate::fetch::{fetch, Client};
+
+    /// Serves `responses` in order on a local port and returns the server's URL.
+    fn stub_server(responses: Vec<(u16, &'static str)>) -> String {
+        let server = Server::http("127.0.0.1:0").expect("Failed to start stub server");
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let request = server.recv().expect("Failed to receive request");
+                let cookie = request.headers().iter()
+                    .find(|h| h.field.equiv("Cookie"))
+                    .map(|h| h.value.to_string());
+                let (status, body) = if cookie.as_deref() == Some("session=secret") {
+                    (status, format!("{} {}", request.url(), body))
+                } else {
+                    (400, "Puzzle inputs differ by user.".to_string())
+                };
+                let header = Header::from_bytes("Content-Type", "text/plain").unwrap();
+                request.respond(Response::from_string(body).with_status_code(status).with_header(header)).unwrap();
+            }
+        });
+        url
+    }
+
+    #[test]
+    fn downloads_input_with_session_cookie() {
+        let url = stub_server(vec![(200, "input")]);
+        let mut client = Client::new(&url, "secret", Duration::ZERO);
+
+        assert_eq!(client.fetch_input(2015, 3).unwrap(), "/2015/day/3/input input");
+    }
+
+    #[test]
+    fn reports_server_errors() {
+        let url = stub_server(vec![(200, ""), (404, ""), (429, "")]);
+        let mut wrong_session = Client::new(&url, "wrong", Duration::ZERO);
+        let mut client = Client::new(&url, "secret", Duration::ZERO);
+
+        assert!(wrong_session.fetch_input(2015, 1).unwrap_err().to_string().ends_with("the session token was rejected"));
+        assert!(client.fetch_input(2015, 1).unwrap_err().to_string().ends_with("the puzzle is not unlocked yet"));
+        assert!(client.fetch_input(2015, 1).unwrap_err().to_string().contains("rate limited"));
+    }
+
+    #[test]
+    fn waits_between_requests() {
+        let url = stub_server(vec![(200, ""), (200, "")]);
+        let mut client = Client::new(&url, "secret", Duration::from_millis(200));
+
+        let start = Instant::now();
+        client.fetch_input(2015, 1).unwrap();
+        client.fetch_input(2015, 2).unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn only_overwrites_cached_inputs_when_forced() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let input_file = |day| dir.path().join(format!("day{}.txt", day)).to_str().unwrap().to_string();
+        fs::write(input_file(1), "cached").unwrap();
+        let url = stub_server(vec![(200, "fresh"), (200, "fresh")]);
+        let client = || Ok(Client::new(&url, "secret", Duration::ZERO));
+
+        fetch(2015, &[1, 2], &input_file, false, client).unwrap();
+        assert_eq!(fs::read_to_string(input_file(1)).unwrap(), "cached");
+        assert_eq!(fs::read_to_string(input_file(2)).unwrap(), "/2015/day/2/input fresh");
+
+        fetch(2015, &[1], &input_file, true, client).unwrap();
+        assert_eq!(fs::read_to_string(input_file(1)).unwrap(), "/2015/day/1/input fresh");
+    }
+}
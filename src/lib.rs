@@ -0,0 +1,18 @@
+//! Solutions for Advent of Code.
+//!
+//! Every year has its own `yearNNNN` module. In there, every day lives in its own module with a
+//! `DayN` type implementing [`solution::Solution`], next to its parsed input types and the
+//! functions behind both parts.
+
+pub mod util;
+pub mod error;
+pub mod solution;
+
+use solution::years;
+
+years! {
+    2015 => year2015,
+}
+
+/// The 2015 solutions, which this crate started out with, stay available at the crate root.
+pub use year2015::*;
@@ -1,79 +1,182 @@
-mod util;
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
+mod cli;
+mod all;
+mod verify;
+mod examples;
+mod bench;
+mod fetch;
+mod watch;
+mod scaffold;
+mod export;
+mod compile;
 
-extern crate dotenv;
-extern crate core;
+use clap::{CommandFactory, Parser};
+use clap::error::ErrorKind;
+use cli::{Cli, Command, Format};
+use serde::{Deserialize, Serialize};
+use advent_of_code::{error, solution, util, YEARS};
+use util::InputSource;
+use solution::{Answer, Part};
 
-use dotenv::dotenv;
-use std::env;
-use std::time::Instant;
+use std::path::Path;
+use std::process::ExitCode;
+use error::Result;
+use solution::{Run, Year};
 
-fn main() {
-    let day_string = env::args().nth(1).or_else(|| {
-        dotenv().ok();
-        env::var("DAY").ok()
-    }).unwrap_or("1".to_string());
+fn main() -> ExitCode {
+    match execute(Cli::parse()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
 
-    let day = day_string.parse::<i32>().expect("Wrong format for day variable");
+/// The solutions of `year`, exiting with a usage error if there are none.
+fn solutions(year: u32) -> &'static Year {
+    YEARS.iter().find(|y| y.year == year).unwrap_or_else(|| {
+        let available = YEARS.iter().map(|y| y.year.to_string()).collect::<Vec<_>>().join(", ");
+        Cli::command()
+            .error(ErrorKind::InvalidValue, format!("there are no solutions for {}, available years: {}", year, available))
+            .exit()
+    })
+}
 
-    if day == 0 {
-        for d in 1..=25 {
-            run(d, env::var("TIME").is_ok());
-            println!()
+fn execute(cli: Cli) -> Result<bool> {
+    let year = cli.year.unwrap_or_else(|| YEARS.last().expect("No years registered").year);
+    match cli.command {
+        Command::Run { days, part, input, time, format } => {
+            if input.is_some() && days.0.len() > 1 {
+                Cli::command()
+                    .error(ErrorKind::ArgumentConflict, "--input can only be used with a single day")
+                    .exit();
+            }
+            let parts = match part {
+                Some(part) => vec![part],
+                None => Part::ALL.to_vec(),
+            };
+            let solutions = solutions(year);
+            let mut records = vec![];
+            let mut ok = true;
+            for (i, day) in days.0.into_iter().enumerate() {
+                let (input_file, run) = match run(solutions, day, &parts, input.as_ref()) {
+                    Ok(Some(result)) => result,
+                    Ok(None) => {
+                        eprintln!("Day {} not yet implemented 😅", day);
+                        continue;
+                    }
+                    Err(e) => {
+                        eprintln!("error: {}", e);
+                        ok = false;
+                        continue;
+                    }
+                };
+                match format {
+                    Format::Text => {
+                        if i > 0 {
+                            println!()
+                        }
+                        print_run(day, &run, time)
+                    }
+                    Format::Json => records.extend(to_records(year, day, &input_file, run)),
+                }
+            }
+            if format == Format::Json {
+                println!("{}", serde_json::to_string_pretty(&records).expect("Failed to serialize records"));
+            }
+            Ok(ok)
+        }
+        Command::All { days, slowest } => all::all(solutions(year), &days.0, slowest),
+        Command::Verify { days, answers } => {
+            let answers = answers.unwrap_or_else(|| verify::answers_file(year));
+            verify::verify(solutions(year), &days.0, &answers)
+        }
+        Command::Examples { days, examples } => {
+            let examples = examples.unwrap_or_else(|| examples::examples_file(year));
+            examples::examples(solutions(year), &days.0, &examples)
+        }
+        Command::Bench { days, warmup, runs, baseline, save_baseline, threshold, size, seed } => {
+            let baseline_file = baseline.unwrap_or_else(|| bench::baseline_file(year));
+            let generated = size.map(|size| (size, seed));
+            let options = bench::Options { warmup, runs, baseline_file, save_baseline, threshold, generated };
+            bench::bench(solutions(year), &days.0, &options)
+        }
+        Command::Generate { day, size, seed } => {
+            let solutions = solutions(year);
+            let Some(solution) = solutions.day(day) else {
+                eprintln!("Day {} not yet implemented 😅", day);
+                return Ok(false);
+            };
+            match (solution.generate)(size, seed)? {
+                Some(input) => println!("{}", input),
+                None => {
+                    eprintln!("Day {} has no input generator", day);
+                    return Ok(false);
+                }
+            }
+            Ok(true)
         }
-    } else {
-        run(day, env::var("TIME").is_ok())
+        Command::Watch { day, release } => watch::watch(solutions(year).year, day, release),
+        Command::New { day } => scaffold::scaffold(Path::new("."), year, day),
+        Command::Export { day, format, input } => {
+            let source = input.unwrap_or_else(|| InputSource::for_day(year, day));
+            export::export(year, day, format, &source)
+        }
+        Command::Compile { language, bits, input } => {
+            let source = input.unwrap_or_else(|| InputSource::for_day(2015, 7));
+            compile::compile(year, language, bits, &source)
+        }
+        Command::Fetch { days, force } => fetch::fetch(year, &days.0, &|day| util::input_file(year, day), force, || {
+            Ok(fetch::Client::new(fetch::BASE_URL, &fetch::session()?, fetch::MIN_INTERVAL))
+        }),
     }
 }
 
-fn run(day: i32, time: bool) {
-    let start = Instant::now();
-    match day {
-        1 => day1::main(),
-        2 => day2::main(),
-        3 => day3::main(),
-        4 => day4::main(),
-        5 => day5::main(),
-        6 => day6::main(),
-        7 => day7::main(),
-        8 => day8::main(),
-        9 => day9::main(),
-        10 => day10::main(),
-        11 => day11::main(),
-        12 => day12::main(),
-        13 => day13::main(),
-        14 => day14::main(),
-        15 => day15::main(),
-        16 => day16::main(),
-        17 => day17::main(),
-        18 => day18::main(),
-        19 => day19::main(),
-        20 => day20::main(),
-        21 => day21::main(),
-        other => { println!("Day {} not yet implemented 😅", other) }
+fn run(solutions: &Year, day: u32, parts: &[Part], input: Option<&InputSource>) -> Result<Option<(String, Run)>> {
+    let Some(solution) = solutions.day(day) else {
+        return Ok(None);
+    };
+    let source = input.cloned().unwrap_or_else(|| InputSource::for_day(solutions.year, day));
+    let name = source.to_string();
+    let run = (solution.solve)(&source.read()?, parts)
+        .map_err(|e| e.in_file(&name))?;
+    Ok(Some((name, run)))
+}
+
+#[derive(Serialize, Deserialize)]
+struct Record {
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: Answer,
+    parse_duration_us: u64,
+    duration_us: u64,
+    input: String,
+}
+
+fn to_records(year: u32, day: u32, input_file: &str, run: Run) -> Vec<Record> {
+    run.parts.into_iter()
+        .map(|result| Record {
+            year,
+            day,
+            part: match result.part {
+                Part::One => 1,
+                Part::Two => 2,
+            },
+            answer: result.answer,
+            parse_duration_us: run.parse.as_micros() as u64,
+            duration_us: result.duration.as_micros() as u64,
+            input: input_file.to_string(),
+        })
+        .collect()
+}
+
+fn print_run(day: u32, run: &Run, time: bool) {
+    for result in &run.parts {
+        println!("Day {}, Part {}: {}", day, result.part, result.answer);
     }
-    let duration = start.elapsed();
     if time {
-        println!("Time: {} ms", duration.as_millis())
+        println!("Time: {} ms", run.total().as_millis())
     }
 }
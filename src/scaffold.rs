@@ -0,0 +1,175 @@
+use std::fs;
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::util;
+
+pub const LIB_FILE: &str = "src/lib.rs";
+
+fn year_file(year: u32) -> String {
+    format!("src/year{}/mod.rs", year)
+}
+
+const TEMPLATE: &str = r#"use crate::error::Result;
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+pub struct DayN;
+
+impl Solution for DayN {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(util::parse_strings(input))
+    }
+
+    fn part1(_input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::Text("not implemented".into()))
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::Text("not implemented".into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::yearY::dayN::DayN;
+    use crate::solution::{Answer, Solution};
+
+    const EXAMPLE: &str = "";
+
+    #[test]
+    #[ignore = "fill in the example from the puzzle statement"]
+    fn solves_example() {
+        let input = DayN::parse(EXAMPLE).unwrap();
+
+        assert_eq!(DayN::part1(&input).unwrap(), Answer::Number(0));
+        assert_eq!(DayN::part2(&input).unwrap(), Answer::Number(0));
+    }
+}
+"#;
+
+fn write_error(path: &Path) -> impl FnOnce(std::io::Error) -> Error + '_ {
+    move |source| Error::Io { path: path.display().to_string(), source }
+}
+
+/// Adds `entry` for `key` to the `name!` registry macro invocation in `source`, keeping the keys in order.
+fn register(source: &str, name: &str, key: u32, entry: &str) -> Result<String> {
+    let entry = format!("    {},", entry);
+    let mut lines = source.lines().collect::<Vec<_>>();
+    let start = lines.iter().position(|l| l.starts_with(&format!("{}! {{", name)))
+        .ok_or_else(|| Error::parse("", format!("expected a `{}! {{` invocation", name)))?;
+    let end = start + lines[start..].iter().position(|l| *l == "}")
+        .ok_or_else(|| Error::parse("", format!("expected the `{}!` invocation to end with `}}`", name)))?;
+    let mut position = end;
+    for (i, line) in lines.iter().enumerate().take(end).skip(start + 1) {
+        let registered = line.split_whitespace().next().and_then(|d| d.parse::<u32>().ok());
+        match registered {
+            Some(k) if k == key => return Err(Error::parse(line.trim(), format!("{} is already registered", key))),
+            Some(k) if k > key => {
+                position = i;
+                break;
+            }
+            _ => {}
+        }
+    }
+    lines.insert(position, &entry);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String> {
+    util::read_file(&path.display().to_string())
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(write_error(parent))?;
+    }
+    fs::write(path, contents).map_err(write_error(path))
+}
+
+/// Creates a module for `day` from the template, an empty input file and registers the day,
+/// along with its year if this is the first day of it.
+pub fn scaffold(root: &Path, year: u32, day: u32) -> Result<bool> {
+    let module = root.join(util::source_file(year, day));
+    let input = root.join(util::input_file(year, day));
+    let year_module = root.join(year_file(year));
+    if module.exists() {
+        return Err(Error::Io {
+            path: module.display().to_string(),
+            source: std::io::Error::new(std::io::ErrorKind::AlreadyExists, "the day already exists"),
+        });
+    }
+
+    if !year_module.exists() {
+        let lib = root.join(LIB_FILE);
+        let registered = register(&read(&lib)?, "years", year, &format!("{} => year{}", year, year))
+            .map_err(|e| e.in_file(&lib.display().to_string()))?;
+        write(&year_module, &format!("//! Solutions for Advent of Code {}.\n\nuse crate::solution::days;\n\ndays! {{\n}}\n", year))?;
+        write(&lib, &registered)?;
+        println!("Registered year {} in {}", year, lib.display());
+    }
+
+    let registered = register(&read(&year_module)?, "days", day, &format!("{} => day{}::Day{}", day, day, day))
+        .map_err(|e| e.in_file(&year_module.display().to_string()))?;
+    let source = TEMPLATE.replace("DayN", &format!("Day{}", day))
+        .replace("dayN", &format!("day{}", day))
+        .replace("yearY", &format!("year{}", year));
+    write(&module, &source)?;
+    println!("Created {}", module.display());
+    if !input.exists() {
+        write(&input, "")?;
+        println!("Created {}", input.display());
+    }
+    write(&year_module, &registered)?;
+    println!("Registered day {} in {}", day, year_module.display());
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use crate::scaffold::{register, scaffold};
+
+    const YEAR: &str = "use crate::solution::days;\n\ndays! {\n    1 => day1::Day1,\n    3 => day3::Day3,\n}\n";
+
+    #[test]
+    fn registers_in_order() {
+        assert_eq!(register(YEAR, "days", 2, "2 => day2::Day2").unwrap(), "use crate::solution::days;\n\ndays! {\n    1 => day1::Day1,\n    2 => day2::Day2,\n    3 => day3::Day3,\n}\n");
+        assert_eq!(register(YEAR, "days", 4, "4 => day4::Day4").unwrap(), "use crate::solution::days;\n\ndays! {\n    1 => day1::Day1,\n    3 => day3::Day3,\n    4 => day4::Day4,\n}\n");
+        assert_eq!(register("years! {\n}\n", "years", 2016, "2016 => year2016").unwrap(), "years! {\n    2016 => year2016,\n}\n");
+        assert!(register(YEAR, "days", 3, "3 => day3::Day3").is_err());
+        assert!(register(YEAR, "years", 2016, "2016 => year2016").is_err());
+    }
+
+    #[test]
+    fn scaffolds_a_new_day() {
+        let root = tempfile::tempdir().expect("Failed to create directory");
+        fs::create_dir_all(root.path().join("src/year2015")).unwrap();
+        fs::write(root.path().join("src/year2015/mod.rs"), YEAR).unwrap();
+
+        scaffold(root.path(), 2015, 2).unwrap();
+
+        let module = fs::read_to_string(root.path().join("src/year2015/day2.rs")).unwrap();
+        assert!(module.contains("impl Solution for Day2 {"));
+        assert!(module.contains("use crate::year2015::day2::Day2;"));
+        assert!(!module.contains("todo!()"));
+        assert_eq!(fs::read_to_string(root.path().join("resources/2015/day2.txt")).unwrap(), "");
+        assert!(fs::read_to_string(root.path().join("src/year2015/mod.rs")).unwrap().contains("    2 => day2::Day2,\n    3 => day3::Day3,"));
+        assert!(scaffold(root.path(), 2015, 2).is_err());
+    }
+
+    #[test]
+    fn scaffolds_a_new_year() {
+        let root = tempfile::tempdir().expect("Failed to create directory");
+        fs::create_dir_all(root.path().join("src")).unwrap();
+        fs::write(root.path().join("src/lib.rs"), "years! {\n    2015 => year2015,\n}\n").unwrap();
+
+        scaffold(root.path(), 2016, 1).unwrap();
+
+        assert_eq!(fs::read_to_string(root.path().join("src/lib.rs")).unwrap(), "years! {\n    2015 => year2015,\n    2016 => year2016,\n}\n");
+        assert!(fs::read_to_string(root.path().join("src/year2016/mod.rs")).unwrap().contains("days! {\n    1 => day1::Day1,\n}"));
+        assert!(root.path().join("src/year2016/day1.rs").exists());
+    }
+}
@@ -0,0 +1,231 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::error;
+
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> error::Result<Self::Input>;
+    fn part1(input: &Self::Input) -> error::Result<Answer>;
+    fn part2(input: &Self::Input) -> error::Result<Answer>;
+
+    /// Changes a number the puzzle statement fixes, such as how long the race lasts, so that
+    /// examples stated with other numbers can be checked. Unknown names are an error.
+    fn configure(_input: &mut Self::Input, name: &str, _value: i64) -> error::Result<()> {
+        Err(error::Error::unknown_setting(name))
+    }
+
+    /// A random input in the puzzle's format that both parts can solve, for stress and scale
+    /// testing. What `size` counts depends on the puzzle, such as lines or cities. Sizes the
+    /// puzzle's format cannot have are an error, `None` means there is no generator.
+    fn generate(_size: usize, _rng: &mut ChaCha8Rng) -> error::Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// A number with how it was reached, such as the spells cast in a fight. Only the number is
+    /// compared with known answers.
+    Explained { number: i64, detail: String },
+}
+
+impl Answer {
+    /// The answer without its explanation, as it is stored in answer files.
+    pub fn unexplained(&self) -> Answer {
+        match self {
+            Answer::Explained { number, .. } => Answer::Number(*number),
+            other => other.clone(),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => n.fmt(f),
+            Answer::Text(s) => f.pad(s),
+            Answer::Explained { number, detail } => f.pad(&format!("{} ({})", number, detail)),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Number(i64::from(n))
+            }
+        })*
+    };
+}
+
+answer_from_number!(u16, u32, i32, i64);
+
+/// Numbers that may not fit in an `i64`, which are an error rather than a wrapped answer.
+macro_rules! answer_try_from_number {
+    ($($t:ty),*) => {
+        $(impl TryFrom<$t> for Answer {
+            type Error = error::Error;
+
+            fn try_from(n: $t) -> error::Result<Self> {
+                i64::try_from(n)
+                    .map(Answer::Number)
+                    .map_err(|_| error::Error::unsolvable(format!("the answer {} does not fit in an i64", n)))
+            }
+        })*
+    };
+}
+
+answer_try_from_number!(u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => Err(format!("there is no part {}, expected 1 or 2", other)),
+        }
+    }
+}
+
+pub struct PartResult {
+    pub part: Part,
+    pub answer: Answer,
+    pub duration: Duration,
+}
+
+pub struct Run {
+    pub parse: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+impl Run {
+    pub fn total(&self) -> Duration {
+        self.parse + self.parts.iter().map(|p| p.duration).sum::<Duration>()
+    }
+}
+
+/// Named numbers to [configure](Solution::configure) a puzzle with.
+pub type Settings = BTreeMap<String, i64>;
+
+pub struct Day {
+    pub day: u32,
+    pub solve: fn(&str, &[Part]) -> error::Result<Run>,
+    pub solve_with: fn(&str, &Settings, &[Part]) -> error::Result<Run>,
+    pub generate: fn(usize, u64) -> error::Result<Option<String>>,
+}
+
+pub struct Year {
+    pub year: u32,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub fn day(&self, day: u32) -> Option<&Day> {
+        self.days.iter().find(|d| d.day == day)
+    }
+}
+
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> error::Result<Run> {
+    solve_with::<S>(input, &Settings::new(), parts)
+}
+
+pub fn solve_with<S: Solution>(input: &str, settings: &Settings, parts: &[Part]) -> error::Result<Run> {
+    let start = Instant::now();
+    let mut input = S::parse(input)?;
+    for (name, value) in settings {
+        S::configure(&mut input, name, *value)?;
+    }
+    let parse = start.elapsed();
+    let parts = parts.iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(&input),
+                Part::Two => S::part2(&input),
+            }?;
+            Ok(PartResult { part: *part, answer, duration: start.elapsed() })
+        })
+        .collect::<error::Result<_>>()?;
+    Ok(Run { parse, parts })
+}
+
+pub fn generate<S: Solution>(size: usize, seed: u64) -> error::Result<Option<String>> {
+    S::generate(size, &mut ChaCha8Rng::seed_from_u64(seed))
+}
+
+/// Declares the day modules and lists them in `DAYS`, so a new day is a single line.
+macro_rules! days {
+    ($($day:literal => $module:ident :: $solution:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Every implemented day, in order.
+        pub const DAYS: &[$crate::solution::Day] = &[
+            $($crate::solution::Day {
+                day: $day,
+                solve: $crate::solution::solve::<$module::$solution>,
+                solve_with: $crate::solution::solve_with::<$module::$solution>,
+                generate: $crate::solution::generate::<$module::$solution>,
+            },)*
+        ];
+    };
+}
+
+pub(crate) use days;
+
+/// Declares the year modules and lists them in `YEARS`.
+macro_rules! years {
+    ($($year:literal => $module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Every year with solutions, in order.
+        pub const YEARS: &[$crate::solution::Year] = &[
+            $($crate::solution::Year { year: $year, days: $module::DAYS },)*
+        ];
+    };
+}
+
+pub(crate) use years;
@@ -1,33 +1,114 @@
-
-use std::fmt::{Debug};
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
 use std::fs;
+use std::io::{self, Read, Write};
 use std::str::FromStr;
 
+use crate::error::{Error, Result};
 
-pub fn parse_strings(file_path: &str) -> Vec<String> {
-    let contents = fs::read_to_string(file_path).expect("File does not exists");
-    contents.lines()
-        .map(|s| s.to_string())
-        .collect()
+
+pub fn resources(year: u32) -> String {
+    format!("resources/{}", year)
+}
+
+pub fn input_file(year: u32, day: u32) -> String {
+    format!("{}/day{}.txt", resources(year), day)
+}
+
+pub fn source_file(year: u32, day: u32) -> String {
+    format!("src/year{}/day{}.rs", year, day)
+}
+
+pub fn read_file(file_path: &str) -> Result<String> {
+    fs::read_to_string(file_path).map_err(|source| Error::Io { path: file_path.to_string(), source })
+}
+
+/// Writes `output` to stdout, treating a closed pipe (e.g. `| head`) as the reader having had enough.
+pub fn write_stdout(output: impl Display) -> Result<()> {
+    let mut stdout = io::stdout().lock();
+    match write!(stdout, "{}", output).and_then(|_| stdout.flush()) {
+        Err(source) if source.kind() != io::ErrorKind::BrokenPipe => Err(Error::Io { path: "<stdout>".to_string(), source }),
+        _ => Ok(()),
+    }
 }
 
-pub fn parse_string(file_path: &str) -> String {
-    let contents = fs::read_to_string(file_path).expect("File does not exists");
-    contents.trim().to_string()
+/// Where a puzzle input comes from. Parses from a path, with `-` meaning stdin.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputSource {
+    File(String),
+    Stdin,
+    Inline(String),
+    Embedded { name: &'static str, contents: &'static str },
 }
 
-pub fn parse_from_string<T: FromStr>(file_path: &str) -> T {
-    let contents = fs::read_to_string(file_path).expect("File does not exists");
-    match contents.trim().parse() {
-        Ok(x) => x,
-        _ => panic!()
+impl InputSource {
+    pub fn for_day(year: u32, day: u32) -> Self {
+        InputSource::File(input_file(year, day))
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)
+                    .map_err(|source| Error::Io { path: self.to_string(), source })?;
+                Ok(input)
+            }
+            InputSource::Inline(input) => Ok(input.clone()),
+            InputSource::Embedded { contents, .. } => Ok(contents.to_string()),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::File(path) => f.pad(path),
+            InputSource::Stdin => f.pad("<stdin>"),
+            InputSource::Inline(_) => f.pad("<inline>"),
+            InputSource::Embedded { name, .. } => f.pad(name),
+        }
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(path.to_string()),
+        })
     }
 }
 
-pub fn parse_from_strings<T: FromStr>(file_path: &str) -> Vec<T> where <T as FromStr>::Err: Debug {
-    let contents = fs::read_to_string(file_path).expect("File does not exists");
-    contents.lines()
-        .map(|s| s.parse().unwrap())
+/// Compiles `resources/YEAR/dayN.txt` of the calling crate into the binary as an [`InputSource`].
+#[macro_export]
+macro_rules! embedded_input {
+    ($year:literal, $day:literal) => {
+        $crate::util::InputSource::Embedded {
+            name: concat!("resources/", $year, "/day", $day, ".txt"),
+            contents: include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/", $year, "/day", $day, ".txt")),
+        }
+    };
+}
+
+pub fn parse_strings(input: &str) -> Vec<String> {
+    input.lines()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+pub fn parse_from_string<T: FromStr>(input: &str) -> Result<T> where Error: From<T::Err> {
+    let input = input.trim();
+    input.parse().map_err(|e| Error::from(e).at_line(1, input))
+}
+
+pub fn parse_from_strings<T: FromStr>(input: &str) -> Result<Vec<T>> where Error: From<T::Err> {
+    input.lines()
+        .enumerate()
+        .map(|(i, s)| s.parse().map_err(|e| Error::from(e).at_line(i + 1, s)))
         .collect()
 }
 
@@ -35,7 +116,7 @@ pub fn parse_from_strings<T: FromStr>(file_path: &str) -> Vec<T> where <T as Fro
 mod tests {
     
     use std::io::Write;
-    use crate::util::{parse_strings};
+    use crate::util::{parse_from_strings, parse_strings, read_file, InputSource};
     use tempfile::NamedTempFile;
 
     #[test]
@@ -44,7 +125,7 @@ mod tests {
         file.write_all("This\nis a\nFile!\n".as_bytes()).expect("Failed to write to file");
         let filename = file.path().to_str().expect("Failed to get file path");
 
-        let strings = parse_strings(filename);
+        let strings = parse_strings(&read_file(filename).unwrap());
 
         let expected_strings: Vec<String> = vec![
             "This".to_string(),
@@ -53,4 +134,29 @@ mod tests {
         ];
         assert_eq!(strings, expected_strings);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn reads_input_sources() {
+        let mut file: NamedTempFile = NamedTempFile::new().expect("Failed to create file");
+        file.write_all("from a file".as_bytes()).expect("Failed to write to file");
+        let filename = file.path().to_str().expect("Failed to get file path");
+
+        let source: InputSource = filename.parse().unwrap();
+        assert_eq!(source.read().unwrap(), "from a file");
+        assert_eq!(source.to_string(), filename);
+
+        assert_eq!("-".parse::<InputSource>().unwrap(), InputSource::Stdin);
+        assert_eq!(InputSource::Inline("inline".to_string()).read().unwrap(), "inline");
+
+        let embedded = crate::embedded_input!(2015, 1);
+        assert_eq!(embedded.to_string(), "resources/2015/day1.txt");
+        assert_eq!(embedded.read().unwrap(), read_file("resources/2015/day1.txt").unwrap());
+    }
+
+    #[test]
+    fn reports_line_of_parse_error() {
+        let error = parse_from_strings::<u32>("1\n2\nthree\n4").unwrap_err();
+
+        assert_eq!(error.to_string(), "line 3: invalid digit found in string\n    | three");
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::path::Path;
+use serde::Deserialize;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Part, Year};
+use crate::util;
+
+pub fn answers_file(year: u32) -> String {
+    format!("{}/answers.toml", util::resources(year))
+}
+
+#[derive(Deserialize, Default, Debug)]
+pub struct Expected {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+impl Expected {
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+    Error,
+}
+
+impl Status {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+            Status::Error => "ERROR",
+        }
+    }
+}
+
+/// Parses the `N` out of a `dayN` table name.
+pub fn parse_day_key(key: &str) -> Result<u32> {
+    key.strip_prefix("day")
+        .and_then(|d| d.parse().ok())
+        .ok_or_else(|| Error::parse(key, "expected a table named `dayN`"))
+}
+
+fn parse_answers(contents: &str) -> Result<HashMap<u32, Expected>> {
+    let answers: HashMap<String, Expected> = toml::from_str(contents)
+        .map_err(|e| Error::parse("", e.message()))?;
+    answers.into_iter()
+        .map(|(key, expected)| Ok((parse_day_key(&key)?, expected)))
+        .collect()
+}
+
+pub fn check(expected: Option<&Answer>, actual: &Answer) -> Status {
+    match expected {
+        Some(expected) if expected.unexplained() == actual.unexplained() => Status::Pass,
+        Some(_) => Status::Fail,
+        None => Status::Missing,
+    }
+}
+
+/// Runs the given days and prints a table comparing them to the answers file.
+/// Returns `false` if any answer differs from the known one.
+pub fn verify(solutions: &Year, days: &[u32], answers_file: &str) -> Result<bool> {
+    let answers = parse_answers(&util::read_file(answers_file)?).map_err(|e| e.in_file(answers_file))?;
+    let no_answers = Expected::default();
+    let mut failures = 0;
+
+    println!("{:>3}  {:>4}  {:<7}  {:<16}  Expected", "Day", "Part", "Status", "Answer");
+    for day in days {
+        let expected = answers.get(day).unwrap_or(&no_answers);
+        let input_file = util::input_file(solutions.year, *day);
+        let solution = solutions.day(*day);
+        let actual = match solution {
+            Some(solution) if Path::new(&input_file).exists() => util::read_file(&input_file)
+                .and_then(|input| (solution.solve)(&input, &Part::ALL))
+                .map(|run| run.parts)
+                .map_err(|e| eprintln!("error: {}", e.in_file(&input_file))),
+            _ => Ok(vec![]),
+        };
+
+        for part in Part::ALL {
+            let expected = expected.get(part);
+            let (status, answer) = match actual.as_ref().map(|a| a.iter().find(|result| result.part == part)) {
+                Err(()) => (Status::Error, String::new()),
+                Ok(Some(result)) => (check(expected, &result.answer), result.answer.to_string()),
+                Ok(None) if solution.is_none() => (Status::Missing, "not implemented".to_string()),
+                Ok(None) => (Status::Missing, "no input".to_string()),
+            };
+            if status == Status::Fail || status == Status::Error {
+                failures += 1;
+            }
+            let expected = expected.map(|e| e.to_string()).unwrap_or_default();
+            println!("{:>3}  {:>4}  {:<7}  {:<16}  {}", day, part, status.label(), answer, expected);
+        }
+    }
+
+    println!();
+    if failures > 0 {
+        println!("{} answer(s) differ from {} or could not be computed", failures, answers_file);
+    } else {
+        println!("No regressions");
+    }
+    Ok(failures == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::Answer;
+    use crate::verify::{check, parse_answers, Status};
+
+    #[test]
+    fn parses_answers() {
+        let answers = parse_answers("[day3]\npart1 = 42\n\n[day11]\npart1 = \"abc\"\npart2 = \"abd\"\n").unwrap();
+
+        assert_eq!(answers[&3].part1, Some(Answer::Number(42)));
+        assert_eq!(answers[&3].part2, None);
+        assert_eq!(answers[&11].part2, Some(Answer::Text("abd".to_string())));
+    }
+
+    #[test]
+    fn checks_answers() {
+        assert_eq!(check(Some(&Answer::Number(1)), &Answer::Number(1)), Status::Pass);
+        assert_eq!(check(Some(&Answer::Number(1)), &Answer::Number(2)), Status::Fail);
+        assert_eq!(check(Some(&Answer::Number(1)), &Answer::Text("1".to_string())), Status::Fail);
+        assert_eq!(check(None, &Answer::Number(1)), Status::Missing);
+
+        let explained = Answer::Explained { number: 226, detail: "Poison, Magic Missile".to_string() };
+        assert_eq!(check(Some(&Answer::Number(226)), &explained), Status::Pass);
+        assert_eq!(check(Some(&Answer::Number(227)), &explained), Status::Fail);
+    }
+
+    #[test]
+    fn rejects_unknown_tables() {
+        assert!(parse_answers("[3]\npart1 = 42\n").is_err());
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+use itertools::Itertools;
+
+use crate::error::{Error, Result};
+use crate::solution::Answer;
+use crate::{util, Record};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// How long the files must stay unchanged before the day is re-run, so that saving several
+/// files at once or an editor writing a file in steps only triggers one run.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Modification times of `paths` and, for directories, of every file below them.
+fn modified(paths: &[&str]) -> BTreeMap<PathBuf, SystemTime> {
+    let mut times = BTreeMap::new();
+    let mut pending = paths.iter().map(PathBuf::from).collect_vec();
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = fs::metadata(&path) else {
+            continue;
+        };
+        if metadata.is_dir() {
+            if let Ok(entries) = fs::read_dir(&path) {
+                pending.extend(entries.filter_map(|e| e.ok()).map(|e| e.path()));
+            }
+        } else if let Ok(time) = metadata.modified() {
+            times.insert(path, time);
+        }
+    }
+    times
+}
+
+/// Waits until the modification times stop changing, and returns the settled times.
+fn settle(paths: &[&str], mut times: BTreeMap<PathBuf, SystemTime>) -> BTreeMap<PathBuf, SystemTime> {
+    loop {
+        thread::sleep(DEBOUNCE);
+        let now = modified(paths);
+        if now == times {
+            return times;
+        }
+        times = now;
+    }
+}
+
+/// Rebuilds and runs the day in a child process, so that changes to its source are picked up.
+fn run(year: u32, day: u32, release: bool) -> Result<Option<Vec<(u8, Answer)>>> {
+    let mut command = Command::new(env!("CARGO"));
+    command.args(["run", "--quiet"]);
+    if release {
+        command.arg("--release");
+    }
+    let output = command.args(["--", "--year", &year.to_string(), "run", &day.to_string(), "--format", "json"])
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|source| Error::Io { path: "cargo".to_string(), source })?;
+    if !output.status.success() {
+        return Ok(None);
+    }
+    let records: Vec<Record> = serde_json::from_slice(&output.stdout)
+        .map_err(|e| Error::parse("", e))?;
+    Ok(Some(records.into_iter().map(|r| (r.part, r.answer)).collect()))
+}
+
+/// Describes each answer, and how it changed since the previous run.
+fn diff(previous: Option<&[(u8, Answer)]>, current: &[(u8, Answer)]) -> Vec<String> {
+    current.iter()
+        .map(|(part, answer)| {
+            let before = previous.and_then(|p| p.iter().find(|(q, _)| q == part)).map(|(_, a)| a);
+            match before {
+                Some(before) if before == answer => format!("Part {}: {} (unchanged)", part, answer),
+                Some(before) => format!("Part {}: {} (CHANGED, was {})", part, answer, before),
+                None => format!("Part {}: {}", part, answer),
+            }
+        })
+        .collect()
+}
+
+/// Re-runs the day whenever the sources it is built from or its input file change, until interrupted.
+pub fn watch(year: u32, day: u32, release: bool) -> Result<bool> {
+    let input = util::input_file(year, day);
+    let paths = ["src", "Cargo.toml", &input];
+    println!("Watching src/, Cargo.toml and {}, press Ctrl-C to stop", input);
+    let mut previous: Option<Vec<(u8, Answer)>> = None;
+    let mut last_modified = None;
+    loop {
+        let modified = modified(&paths);
+        if last_modified.as_ref() != Some(&modified) {
+            last_modified = Some(settle(&paths, modified));
+            println!();
+            println!("Running day {}...", day);
+            match run(year, day, release)? {
+                Some(answers) => {
+                    for line in diff(previous.as_deref(), &answers) {
+                        println!("{}", line);
+                    }
+                    previous = Some(answers);
+                }
+                None => println!("Day {} failed, waiting for changes", day),
+            }
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use crate::solution::Answer;
+    use crate::watch::{diff, modified};
+
+    #[test]
+    fn diffs_answers() {
+        let previous = [(1, Answer::Number(1)), (2, Answer::Number(2))];
+        let current = [(1, Answer::Number(1)), (2, Answer::Number(3))];
+
+        assert_eq!(diff(None, &current), vec!["Part 1: 1", "Part 2: 3"]);
+        assert_eq!(diff(Some(&previous), &current), vec!["Part 1: 1 (unchanged)", "Part 2: 3 (CHANGED, was 2)"]);
+    }
+
+    #[test]
+    fn finds_files_below_directories() {
+        let root = tempfile::tempdir().expect("Failed to create directory");
+        let src = root.path().join("src");
+        fs::create_dir_all(src.join("year2015")).unwrap();
+        fs::write(src.join("util.rs"), "").unwrap();
+        fs::write(src.join("year2015").join("day21.rs"), "").unwrap();
+        let input = root.path().join("day22.txt");
+        fs::write(&input, "").unwrap();
+
+        let times = modified(&[src.to_str().unwrap(), input.to_str().unwrap(), "missing.txt"]);
+
+        assert_eq!(times.into_keys().collect::<Vec<_>>(), [input, src.join("util.rs"), src.join("year2015").join("day21.rs")]);
+    }
+}
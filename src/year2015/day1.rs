@@ -0,0 +1,101 @@
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let input = input.trim();
+        match input.chars().position(|c| c != '(' && c != ')') {
+            Some(i) => Err(Error::parse(input, format!("unexpected character at position {}", i + 1))),
+            None => Ok(input.to_string()),
+        }
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(final_floor(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        basement_index(input)
+            .map(Answer::try_from)
+            .ok_or_else(|| Error::unsolvable("Santa never enters the basement"))?
+    }
+
+    /// `size` random instructions that reach the basement at some point.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        let mut instructions: String = (0..size).map(|_| if rng.random_bool(0.5) { '(' } else { ')' }).collect();
+        let floors = instructions.chars()
+            .scan(0, |floor, c| {
+                *floor += if c == '(' { 1 } else { -1 };
+                Some(*floor)
+            })
+            .collect::<Vec<i32>>();
+        if floors.iter().all(|floor| *floor >= 0) {
+            instructions.push_str(&")".repeat(floors.last().copied().unwrap_or_default() as usize + 1));
+        }
+        Ok(Some(instructions))
+    }
+}
+
+pub fn final_floor(instructions: &str) -> i32 {
+    let mut floor = 0;
+    for c in instructions.chars() {
+        match c {
+            '(' => floor += 1,
+            _ => floor -= 1,
+        }
+    }
+    floor
+}
+
+pub fn basement_index(instructions: &str) -> Option<usize> {
+    let mut floor = 0;
+    for (i, c) in instructions.chars().enumerate() {
+        match c {
+            '(' => floor += 1,
+            _ => floor -= 1,
+        }
+        if floor == -1 {
+            return Some(i + 1);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day1::{basement_index, final_floor, Day1};
+    use crate::solution::Solution;
+
+    #[test]
+    fn finds_final_floor() {
+        assert_eq!(final_floor("(())"), 0);
+        assert_eq!(final_floor("()()"), 0);
+        assert_eq!(final_floor("((("), 3);
+        assert_eq!(final_floor("))((((("), 3);
+        assert_eq!(final_floor("())"), -1);
+        assert_eq!(final_floor(")())())"), -3);
+    }
+
+    #[test]
+    fn finds_basement_index() {
+        assert_eq!(basement_index(")"), Some(1));
+        assert_eq!(basement_index("()())"), Some(5));
+    }
+
+    #[test]
+    fn reports_never_entering_the_basement() {
+        assert_eq!(Day1::part2(&Day1::parse("(((").unwrap()).unwrap_err().to_string(), "no answer: Santa never enters the basement");
+    }
+
+    #[test]
+    fn rejects_other_characters() {
+        assert!(Day1::parse("(()x").is_err());
+    }
+}
@@ -0,0 +1,93 @@
+
+
+
+
+use itertools::{iterate, Itertools};
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Sequence;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Sequence { digits: input.trim().to_string(), steps: [40, 50] })
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(repeat_look_and_say(&input.digits, input.steps[0]))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(repeat_look_and_say(&input.digits, input.steps[1]))
+    }
+
+    /// `steps` sets how many times both parts look and say.
+    fn configure(input: &mut Self::Input, name: &str, value: i64) -> Result<()> {
+        match name {
+            "steps" => input.steps = [Error::check_setting(name, value, 0)?; 2],
+            _ => return Err(Error::unknown_setting(name)),
+        }
+        Ok(())
+    }
+
+    /// A sequence of `size` digits, at least one.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        Ok(Some((0..Error::check_size(size, 1..)?).map(|_| rng.random_range('1'..='3')).collect()))
+    }
+}
+
+/// The starting digits and how many times each part looks and says them.
+#[derive(Debug)]
+pub struct Sequence {
+    pub digits: String,
+    pub steps: [usize; 2],
+}
+
+pub fn repeat_look_and_say(string: &str, times: usize) -> usize {
+    iterate(string.to_string(), |s| look_and_say(s))
+        .take(times + 1)
+        .last()
+        .unwrap()
+        .chars()
+        .count()
+}
+
+pub fn look_and_say(string: &str) -> String {
+    string
+        .chars()
+        .group_by(|x| *x)
+        .into_iter()
+        .map(|(c, seq)| format!("{}{}", seq.count(), c))
+        .join("")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day10::{look_and_say, repeat_look_and_say, Day10};
+    use crate::solution::{Answer, Solution};
+
+    #[test]
+    fn looks_and_says() {
+        assert_eq!(look_and_say("1"), "11");
+        assert_eq!(look_and_say("11"), "21");
+        assert_eq!(look_and_say("21"), "1211");
+        assert_eq!(look_and_say("1211"), "111221");
+        assert_eq!(look_and_say("111221"), "312211");
+        assert_eq!(repeat_look_and_say("1", 5), 6);
+    }
+
+    #[test]
+    fn looks_and_says_the_configured_number_of_times() {
+        let mut sequence = Day10::parse("1").unwrap();
+
+        Day10::configure(&mut sequence, "steps", 5).unwrap();
+
+        assert_eq!(Day10::part1(&sequence).unwrap(), Answer::Number(6));
+        assert_eq!(Day10::part2(&sequence).unwrap(), Answer::Number(6));
+    }
+}
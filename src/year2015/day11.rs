@@ -0,0 +1,147 @@
+
+
+
+
+
+use itertools::{Itertools};
+use pathfinding::num_traits::pow;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+
+/// The shortest password that can be valid, such as `aabcc`. Shorter ones have no next password.
+const MIN_LETTERS: usize = 5;
+/// Passwords are counted through as numbers in base 26, which fit a `u128` up to this length.
+const MAX_LETTERS: usize = 27;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let input = input.trim();
+        if input.is_empty() || !input.chars().all(|c| c.is_ascii_lowercase()) {
+            return Err(Error::parse(input, "expected a password of lowercase letters"));
+        }
+        if !(MIN_LETTERS..=MAX_LETTERS).contains(&input.len()) {
+            let message = format!("expected a password of {} to {} letters", MIN_LETTERS, MAX_LETTERS);
+            return Err(Error::parse("", message).at_line(1, input));
+        }
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(next_password(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(next_password(&next_password(input)).into())
+    }
+
+    /// A password of `size` letters, between 5 and 27. It ends in `aaaaa`, so that the next passwords
+    /// are only a few thousand increments away.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        let letters = ('a'..='z').filter(|c| !"iol".contains(*c)).collect_vec();
+        let prefix: String = (5..Error::check_size(size, MIN_LETTERS..=MAX_LETTERS)?).map(|_| letters[rng.random_range(0..letters.len())]).collect();
+        Ok(Some(prefix + "aaaaa"))
+    }
+}
+
+pub fn next_password(start: &str) -> String {
+    let n = start.len();
+    for pw_number in from_password(start) + 1.. {
+        let pw = to_password(&(pw_number % pow(26, n)), n);
+        if is_valid(&pw) {
+            return pw;
+        }
+    }
+    panic!()
+}
+
+pub fn is_valid(pw: &str) -> bool {
+    has_straight(pw) && !has_i_o_l(pw) && has_two_repeats(pw)
+}
+
+fn has_straight(pw: &str) -> bool {
+    pw.bytes()
+        .tuple_windows::<(u8, u8, u8)>()
+        .any(|(a, b, c)| b == a + 1 && c == b + 1)
+}
+
+fn has_i_o_l(pw: &str) -> bool {
+    pw.chars().any(|c| "iol".contains(c))
+}
+
+fn has_two_repeats(pw: &str) -> bool {
+    let double_indices = pw.chars()
+        .tuple_windows::<(char, char)>()
+        .enumerate()
+        .filter(|(_, (a, b))| a == b)
+        .map(|(i, _)| i)
+        .collect_vec();
+    match double_indices[..] {
+        [] | [_] => false,
+        [a, b] => a.abs_diff(b) > 1,
+        _ => true
+    }
+}
+
+fn to_password(x: &u128, letters: usize) -> String {
+    let mut result = vec![];
+    let mut x = *x;
+
+    for _ in 0..letters {
+        result.push(char::from_u32('a' as u32 + (x % 26) as u32).unwrap());
+        x /= 26;
+    }
+    result.into_iter().rev().collect()
+}
+
+fn from_password(password: &str) -> u128 {
+    password.chars()
+        .rev()
+        .enumerate()
+        .map(|(i, c)| (c as u128 - 'a' as u128) * pow(26, i))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day11::{from_password, is_valid, next_password, to_password, Day11};
+    use crate::solution::Solution;
+
+    #[test]
+    fn validates_passwords() {
+        assert!(!is_valid("hijklmmn"));
+        assert!(!is_valid("abbceffg"));
+        assert!(!is_valid("abbcegjk"));
+        assert!(is_valid("abcdffaa"));
+        assert!(is_valid("ghjaabcc"));
+    }
+
+    #[test]
+    fn finds_next_password() {
+        assert_eq!(next_password("abcdefgh"), "abcdffaa");
+        assert_eq!(next_password("abcdffaa"), "abcdffbb");
+    }
+
+    #[test]
+    fn converts_long_passwords() {
+        assert_eq!(to_password(&from_password("zzzzzzzzzz"), 10), "zzzzzzzzzz");
+        assert_eq!(to_password(&from_password("qrstuvwxyz"), 10), "qrstuvwxyz");
+        assert_eq!(to_password(&from_password(&"z".repeat(27)), 27), "z".repeat(27));
+    }
+
+    #[test]
+    fn rejects_passwords_that_are_too_short_or_too_long() {
+        assert_eq!(
+            Day11::parse(&"a".repeat(28)).unwrap_err().to_string(),
+            format!("line 1: expected a password of 5 to 27 letters\n    | {}", "a".repeat(28)),
+        );
+        assert_eq!(Day11::parse("abcd").unwrap_err().to_string(), "line 1: expected a password of 5 to 27 letters\n    | abcd");
+        assert_eq!(next_password(&Day11::parse("aabcb").unwrap()), "aabcc");
+    }
+}
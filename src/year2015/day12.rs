@@ -0,0 +1,136 @@
+use serde_json::{Value};
+use itertools::{Itertools};
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Value;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let json = serde_json::from_str(input).map_err(|e| {
+            let number = e.line();
+            let line = input.lines().nth(number.saturating_sub(1)).unwrap_or_default();
+            Error::parse("", e).at_line(number, line)
+        })?;
+        check_integers(&json)?;
+        Ok(json)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        report(add_numbers(input, false))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        report(add_numbers(input, true))
+    }
+
+    /// A JSON document with about `size` values, at least two.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        Ok(Some(random_json(Error::check_size(size, 2..)?, rng)))
+    }
+}
+
+fn random_json(size: usize, rng: &mut ChaCha8Rng) -> String {
+    if size <= 1 {
+        return if rng.random_bool(0.7) {
+            rng.random_range(-100..=200).to_string()
+        } else {
+            format!("\"{}\"", ["red", "green", "blue", "yellow", "violet"][rng.random_range(0..5)])
+        };
+    }
+    let count = rng.random_range(1..=(size - 1).min(5));
+    let values = (0..count)
+        .map(|i| random_json((size - 1) / count + usize::from(i < (size - 1) % count), rng))
+        .collect_vec();
+    if rng.random_bool(0.5) {
+        format!("[{}]", values.join(","))
+    } else {
+        format!("{{{}}}", values.iter().enumerate().map(|(i, v)| format!("\"{}\":{}", (b'a' + i as u8) as char, v)).join(","))
+    }
+}
+
+/// Fails on the first number in `json` that is not an integer, as those cannot be added up exactly.
+fn check_integers(json: &Value) -> Result<()> {
+    match json {
+        Value::Number(n) if n.as_i64().is_none() => Err(Error::parse(&n.to_string(), "expected an integer")),
+        Value::Array(values) => values.iter().try_for_each(check_integers),
+        Value::Object(mappings) => mappings.values().try_for_each(check_integers),
+        _ => Ok(()),
+    }
+}
+
+fn report(sum: Option<i64>) -> Result<Answer> {
+    sum.map(Answer::from).ok_or_else(|| Error::unsolvable("the sum of the numbers does not fit in 64 bits"))
+}
+
+/// Adds up the numbers in `json`, which must all be integers, or `None` if the sum overflows.
+/// Booleans and `null` count as 0.
+pub fn add_numbers(json: &Value, ignore_red: bool) -> Option<i64> {
+    match json {
+        Value::Number(n) => Some(n.as_i64().expect("Numbers are checked when parsing")),
+        Value::Null | Value::Bool(_) | Value::String(_) => Some(0),
+        Value::Array(values) => add_all(values.iter(), ignore_red),
+        Value::Object(mappings) if ignore_red
+            && mappings.values().contains(&Value::String("red".to_string())) => Some(0),
+        Value::Object(mappings) => add_all(mappings.values(), ignore_red),
+    }
+}
+
+fn add_all<'a>(mut values: impl Iterator<Item = &'a Value>, ignore_red: bool) -> Option<i64> {
+    values.try_fold(0i64, |sum, v| sum.checked_add(add_numbers(v, ignore_red)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day12::{add_numbers, Day12};
+    use crate::solution::Solution;
+
+    fn sum(json: &str, ignore_red: bool) -> i64 {
+        add_numbers(&Day12::parse(json).unwrap(), ignore_red).unwrap()
+    }
+
+    #[test]
+    fn adds_all_numbers() {
+        assert_eq!(sum("[1,2,3]", false), 6);
+        assert_eq!(sum(r#"{"a":2,"b":4}"#, false), 6);
+        assert_eq!(sum("[[[3]]]", false), 3);
+        assert_eq!(sum(r#"{"a":{"b":4},"c":-1}"#, false), 3);
+        assert_eq!(sum(r#"{"a":[-1,1]}"#, false), 0);
+        assert_eq!(sum(r#"[-1,{"a":1}]"#, false), 0);
+        assert_eq!(sum("[]", false), 0);
+        assert_eq!(sum("{}", false), 0);
+    }
+
+    #[test]
+    fn ignores_red_objects() {
+        assert_eq!(sum("[1,2,3]", true), 6);
+        assert_eq!(sum(r#"[1,{"c":"red","b":2},3]"#, true), 4);
+        assert_eq!(sum(r#"{"d":"red","e":[1,2,3,4],"f":5}"#, true), 0);
+        assert_eq!(sum(r#"[1,"red",5]"#, true), 6);
+    }
+
+    #[test]
+    fn counts_other_values_as_zero() {
+        assert_eq!(sum(r#"[1,true,false,null,{"a":null}]"#, false), 1);
+    }
+
+    #[test]
+    fn rejects_non_integer_numbers() {
+        assert_eq!(Day12::parse("[1,{\"a\":1.5}]").unwrap_err().to_string(), "expected an integer\n    | 1.5");
+        assert!(Day12::parse("[18446744073709551615]").is_err());
+    }
+
+    #[test]
+    fn reports_sums_that_overflow() {
+        let json = Day12::parse("[9223372036854775807,1]").unwrap();
+
+        assert_eq!(Day12::part1(&json).unwrap_err().to_string(), "no answer: the sum of the numbers does not fit in 64 bits");
+        assert_eq!(add_numbers(&Day12::parse("[-9223372036854775808,-1]").unwrap(), false), None);
+    }
+}
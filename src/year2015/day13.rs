@@ -0,0 +1,168 @@
+use std::collections::{HashMap};
+use std::str::FromStr;
+use itertools::{Itertools};
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Happiness>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        util::parse_from_strings(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        report(maximize_total_happiness(input, false))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        report(maximize_total_happiness(input, true))
+    }
+
+    /// The happiness between all of `size` guests, at least two.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        Ok(Some((0..Error::check_size(size, 2..)?)
+            .permutations(2)
+            .map(|pair| {
+                let change: i32 = rng.random_range(-100..=100);
+                let direction = if change < 0 { "lose" } else { "gain" };
+                format!("Guest{} would {} {} happiness units by sitting next to Guest{}.", pair[0], direction, change.abs(), pair[1])
+            })
+            .join("\n")))
+    }
+}
+
+fn report(happiness: Result<Option<i32>>) -> Result<Answer> {
+    happiness?
+        .map(Answer::from)
+        .ok_or_else(|| Error::unsolvable("no seating has a known happiness for every pair of neighbours"))
+}
+
+/// The happiness of the best seating, if any seating has a known happiness for every pair of
+/// neighbours. Fails if the happiness of a seating does not fit in an `i32`.
+pub fn maximize_total_happiness(input: &[Happiness], include_self: bool) -> Result<Option<i32>> {
+    let lookup: HashMap<(String, String), i32> = input
+        .iter()
+        .map(|Happiness { name, other, gain }| ((name.clone(), other.clone()), *gain))
+        .collect();
+    let names = lookup
+        .keys()
+        .map(|(a, _)| a)
+        .unique()
+        .cloned()
+        .collect_vec();
+    if include_self {
+        let mut including_self = input.to_vec();
+        for name in names {
+            including_self.push(Happiness{
+                name: name.clone(),
+                other: "self".to_string(),
+                gain: 0,
+            });
+            including_self.push(Happiness{
+                name: "self".to_string(),
+                other: name.clone(),
+                gain: 0,
+            });
+        }
+        return maximize_total_happiness(&including_self, false)
+    }
+
+    itertools::process_results(
+        names
+            .iter()
+            .permutations(names.len())
+            .map(|v| seating_happiness(&lookup, &v)),
+        |happiness| happiness.flatten().max(),
+    )
+}
+
+/// The happiness of seating `guests` around the table in order, if it is known for every pair
+/// of neighbours.
+fn seating_happiness(lookup: &HashMap<(String, String), i32>, guests: &[&String]) -> Result<Option<i32>> {
+    let mut sum = 0i32;
+    for i in 0..guests.len() {
+        let a = guests[i];
+        let b = guests[(i + 1) % guests.len()];
+        let (Some(a_to_b), Some(b_to_a)) = (lookup.get(&(a.clone(), b.clone())), lookup.get(&(b.clone(), a.clone()))) else {
+            return Ok(None);
+        };
+        sum = [*a_to_b, *b_to_a]
+            .into_iter()
+            .try_fold(sum, i32::checked_add)
+            .ok_or_else(|| Error::unsolvable("the happiness of a seating does not fit in an i32"))?;
+    }
+    Ok(Some(sum))
+}
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Debug)]
+pub struct Happiness {
+    pub name: String,
+    pub other: String,
+    pub gain: i32,
+}
+
+impl FromStr for Happiness {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if let [name, "would", direction @ ("gain" | "lose"), magnitude, "happiness", "units", "by", "sitting", "next", "to", other] = s.split_whitespace().collect_vec()[..] {
+            if let Some(other) = other.strip_suffix('.') {
+                return Ok(Happiness {
+                    name: name.to_string(),
+                    other: other.to_string(),
+                    gain: magnitude.parse::<i32>()? * if direction == "gain" { 1 } else { -1 },
+                });
+            }
+        }
+        Err(Error::parse(s, "expected `<name> would gain|lose <n> happiness units by sitting next to <name>.`"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day13::{maximize_total_happiness, Day13};
+    use crate::solution::Solution;
+
+    const EXAMPLE: &str = "Alice would gain 54 happiness units by sitting next to Bob.
+Alice would lose 79 happiness units by sitting next to Carol.
+Alice would lose 2 happiness units by sitting next to David.
+Bob would gain 83 happiness units by sitting next to Alice.
+Bob would lose 7 happiness units by sitting next to Carol.
+Bob would lose 63 happiness units by sitting next to David.
+Carol would lose 62 happiness units by sitting next to Alice.
+Carol would gain 60 happiness units by sitting next to Bob.
+Carol would gain 55 happiness units by sitting next to David.
+David would gain 46 happiness units by sitting next to Alice.
+David would lose 7 happiness units by sitting next to Bob.
+David would gain 41 happiness units by sitting next to Carol.";
+
+    #[test]
+    fn seats_example_guests() {
+        assert_eq!(maximize_total_happiness(&Day13::parse(EXAMPLE).unwrap(), false).unwrap(), Some(330));
+    }
+
+    #[test]
+    fn reports_missing_happiness() {
+        let guests = Day13::parse("Alice would gain 54 happiness units by sitting next to Bob.").unwrap();
+
+        assert_eq!(
+            Day13::part1(&guests).unwrap_err().to_string(),
+            "no answer: no seating has a known happiness for every pair of neighbours",
+        );
+    }
+
+    #[test]
+    fn reports_happiness_that_overflows() {
+        let guests = Day13::parse("Alice would gain 2147483647 happiness units by sitting next to Bob.
+Bob would gain 2147483647 happiness units by sitting next to Alice.").unwrap();
+
+        assert_eq!(Day13::part1(&guests).unwrap_err().to_string(), "no answer: the happiness of a seating does not fit in an i32");
+    }
+}
@@ -0,0 +1,159 @@
+use std::cmp::{min};
+
+use std::str::FromStr;
+use itertools::{Itertools};
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Race;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let reindeer: Vec<Reindeer> = util::parse_from_strings(input)?;
+        if reindeer.is_empty() {
+            return Err(Error::parse("", "expected at least one reindeer"));
+        }
+        Ok(Race { reindeer, seconds: 2503 })
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(winning_reindeer_after(&input.reindeer, input.seconds))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(winning_reindeer_after_2(&input.reindeer, input.seconds).into())
+    }
+
+    fn configure(input: &mut Self::Input, name: &str, value: i64) -> Result<()> {
+        match name {
+            "seconds" => input.seconds = Error::check_setting(name, value, 0)?,
+            _ => return Err(Error::unknown_setting(name)),
+        }
+        Ok(())
+    }
+
+    /// `size` reindeer, at least one.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        Ok(Some((0..Error::check_size(size, 1..)?)
+            .map(|i| format!(
+                "Reindeer{} can fly {} km/s for {} seconds, but then must rest for {} seconds.",
+                i, rng.random_range(1..=30), rng.random_range(1..=20), rng.random_range(10..=200),
+            ))
+            .join("\n")))
+    }
+}
+
+/// The reindeer and how long they race for.
+#[derive(Debug)]
+pub struct Race {
+    pub reindeer: Vec<Reindeer>,
+    pub seconds: u32,
+}
+
+pub fn winning_reindeer_after(reindeers: &[Reindeer], total_time: u32) -> u64 {
+    reindeers
+        .iter()
+        .map(|r| r.distance_after(total_time))
+        .max().unwrap()
+}
+
+pub fn winning_reindeer_after_2(reindeers: &[Reindeer], total_time: u32) -> u32 {
+    let mut points = reindeers.iter().map(|_| 0u32).collect_vec();
+    for t in 1..=total_time {
+        let distances = reindeers
+            .iter()
+            .map(|r| r.distance_after(t))
+            .collect_vec();
+        let max = distances.iter().max().unwrap();
+        distances
+            .iter()
+            .positions(|d| d == max)
+            .for_each(|idx| points[idx] += 1);
+    }
+    *points.iter().max().unwrap()
+}
+
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Debug)]
+pub struct Reindeer {
+    pub speed: u32,
+    pub endurance: u32,
+    pub rest_time: u32,
+}
+
+impl Reindeer {
+    /// The distance flown, which for a fast reindeer does not fit in a `u32`.
+    pub fn distance_after(&self, time: u32) -> u64 {
+        let (time, endurance) = (u64::from(time), u64::from(self.endurance));
+        let cycle_length = endurance + u64::from(self.rest_time);
+        let full_cycles = time / cycle_length;
+        let remaining_active_time = min(time % cycle_length, endurance);
+        let active_time = (full_cycles * endurance) + remaining_active_time;
+        active_time * u64::from(self.speed)
+    }
+}
+
+impl FromStr for Reindeer {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (speed, endurance, rest_time) = s.split_whitespace()
+            .filter_map(|s| s.parse::<u32>().ok())
+            .collect_tuple()
+            .ok_or_else(|| Error::parse(s, "expected a speed, a flight time and a rest time"))?;
+        if endurance == 0 && rest_time == 0 {
+            return Err(Error::parse(s, "expected a reindeer that flies or rests"));
+        }
+        Ok(Reindeer { speed, endurance, rest_time })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day14::{winning_reindeer_after, winning_reindeer_after_2, Day14};
+    use crate::solution::{Answer, Solution};
+
+    const EXAMPLE: &str = "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.
+Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.";
+
+    #[test]
+    fn races_comet_and_dancer() {
+        let reindeers = Day14::parse(EXAMPLE).unwrap().reindeer;
+
+        assert_eq!(reindeers[0].distance_after(1000), 1120);
+        assert_eq!(reindeers[1].distance_after(1000), 1056);
+        assert_eq!(winning_reindeer_after(&reindeers, 1000), 1120);
+        assert_eq!(winning_reindeer_after_2(&reindeers, 1000), 689);
+    }
+
+    #[test]
+    fn races_extreme_reindeer_without_overflowing() {
+        let reindeers = Day14::parse("Fast can fly 4294967295 km/s for 4294967295 seconds, but then must rest for 4294967295 seconds.").unwrap().reindeer;
+
+        assert_eq!(winning_reindeer_after(&reindeers, 2503), 2503 * 4294967295);
+        assert_eq!(winning_reindeer_after_2(&reindeers, 2503), 2503);
+    }
+
+    #[test]
+    fn races_for_the_configured_time() {
+        let mut race = Day14::parse(EXAMPLE).unwrap();
+
+        Day14::configure(&mut race, "seconds", 1000).unwrap();
+
+        assert_eq!(Day14::part1(&race).unwrap(), Answer::Number(1120));
+        assert_eq!(Day14::part2(&race).unwrap(), Answer::Number(689));
+        assert!(Day14::configure(&mut race, "seconds", -1).is_err());
+        assert!(Day14::configure(&mut race, "reindeer", 3).is_err());
+    }
+
+    #[test]
+    fn rejects_a_race_without_reindeer() {
+        assert!(Day14::parse("").is_err());
+    }
+}
@@ -0,0 +1,177 @@
+use std::cmp::{max};
+
+use std::str::FromStr;
+use itertools::{Itertools};
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+/// The largest property or calorie count an ingredient may have, either way. With 100 teaspoons
+/// the totals stay within 50000, so the score of any cookie fits in an `i64`.
+const MAX_PROPERTY: i32 = 500;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Ingredient>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let ingredients: Vec<Ingredient> = util::parse_from_strings(input)?;
+        if ingredients.is_empty() {
+            return Err(Error::parse("", "expected at least one ingredient"));
+        }
+        Ok(ingredients)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(highest_scoring_cookie(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        highest_scoring_cookie_with_calories(input, 500)
+            .map(Answer::from)
+            .ok_or_else(|| Error::unsolvable("no cookie has exactly 500 calories"))
+    }
+
+    /// `size` ingredients, at least one. The first one has 5 calories and only positive properties,
+    /// so that a cookie of just it has 500 calories and a positive score.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        Ok(Some((0..Error::check_size(size, 1..)?)
+            .map(|i| {
+                let properties = if i == 0 { 1..=5 } else { -5..=5 };
+                format!(
+                    "Ingredient{}: capacity {}, durability {}, flavor {}, texture {}, calories {}",
+                    i, rng.random_range(properties.clone()), rng.random_range(properties.clone()),
+                    rng.random_range(properties.clone()), rng.random_range(properties),
+                    if i == 0 { 5 } else { rng.random_range(1..=9) },
+                )
+            })
+            .join("\n")))
+    }
+}
+
+pub fn highest_scoring_cookie(ingredients: &[Ingredient]) -> i64 {
+    splits(ingredients.len() as i32, 100).iter()
+        .map(|split| score(split, ingredients))
+        .max().unwrap()
+}
+
+pub fn highest_scoring_cookie_with_calories(ingredients: &[Ingredient], calories: i32) -> Option<i64> {
+    splits(ingredients.len() as i32, 100).iter()
+        .filter(|split| count_calories(split, ingredients) == calories)
+        .map(|split| score(split, ingredients))
+        .max()
+}
+
+fn score(split: &[i32], ingredients: &[Ingredient]) -> i64 {
+    let mut capacity = 0;
+    let mut durability = 0;
+    let mut flavor = 0;
+    let mut texture = 0;
+
+    for (amount, ingredient) in split.iter().zip_eq(ingredients.iter()) {
+        capacity += amount * ingredient.capacity;
+        durability += amount * ingredient.durability;
+        flavor += amount * ingredient.flavor;
+        texture += amount * ingredient.texture;
+    }
+
+    [capacity, durability, flavor, texture].iter()
+        .map(|property| max(*property, 0) as i64)
+        .product()
+}
+
+fn count_calories(split: &[i32], ingredients: &[Ingredient]) -> i32 {
+    split.iter().zip_eq(ingredients.iter())
+        .map(|(amount, ingredient)| amount * ingredient.calories)
+        .sum()
+}
+
+fn splits(n: i32, target: i32) -> Vec<Vec<i32>> {
+    if n == 1 {
+        return vec![vec![target]];
+    }
+    let mut vecs = vec![];
+    for i in 0..=target {
+        for mut split in splits(n - 1, target - i) {
+            split.push(i);
+            vecs.push(split);
+        }
+    }
+    vecs
+}
+
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Debug)]
+pub struct Ingredient {
+    pub capacity: i32,
+    pub durability: i32,
+    pub flavor: i32,
+    pub texture: i32,
+    pub calories: i32,
+}
+
+impl FromStr for Ingredient {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (capacity, durability, flavor, texture, calories) = s.split_whitespace()
+            .filter_map(|s| s.trim_end_matches(',').parse::<i32>().ok())
+            .collect_tuple()
+            .ok_or_else(|| Error::parse(s, "expected capacity, durability, flavor, texture and calories"))?;
+        if [capacity, durability, flavor, texture, calories].iter().any(|property| !(-MAX_PROPERTY..=MAX_PROPERTY).contains(property)) {
+            return Err(Error::parse(s, format!("expected properties between -{0} and {0}", MAX_PROPERTY)));
+        }
+        Ok(Ingredient { capacity, durability, flavor, texture, calories })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day15::{highest_scoring_cookie, highest_scoring_cookie_with_calories, Day15};
+    use crate::solution::Solution;
+
+    const EXAMPLE: &str = "Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3";
+
+    #[test]
+    fn bakes_example_cookies() {
+        let ingredients = Day15::parse(EXAMPLE).unwrap();
+
+        assert_eq!(highest_scoring_cookie(&ingredients), 62842880);
+        assert_eq!(highest_scoring_cookie_with_calories(&ingredients, 500), Some(57600000));
+    }
+
+    #[test]
+    fn scores_cookies_beyond_32_bits() {
+        let ingredients = Day15::parse("Sugar: capacity 10, durability 10, flavor 10, texture 10, calories 5").unwrap();
+
+        assert_eq!(highest_scoring_cookie(&ingredients), 1_000_000_000_000);
+    }
+
+    #[test]
+    fn reports_recipes_without_500_calories() {
+        let ingredients = Day15::parse("Sugar: capacity 10, durability 10, flavor 10, texture 10, calories 4").unwrap();
+
+        assert_eq!(Day15::part2(&ingredients).unwrap_err().to_string(), "no answer: no cookie has exactly 500 calories");
+    }
+
+    #[test]
+    fn rejects_properties_beyond_the_limit() {
+        assert!(Day15::parse("Sugar: capacity 500, durability -500, flavor 500, texture 500, calories -500").is_ok());
+        assert_eq!(
+            Day15::parse("Sugar: capacity 100000000, durability 1, flavor 1, texture 1, calories 5").unwrap_err().to_string(),
+            "line 1: expected properties between -500 and 500\n    | Sugar: capacity 100000000, durability 1, flavor 1, texture 1, calories 5",
+        );
+        assert!(Day15::parse("Sugar: capacity 1, durability 1, flavor 1, texture 1, calories 501").is_err());
+        assert!(Day15::parse("Sugar: capacity -2147483648, durability 1, flavor 1, texture 1, calories 5").is_err());
+    }
+
+    #[test]
+    fn rejects_recipes_without_ingredients() {
+        assert!(Day15::parse("").is_err());
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+
+use std::str::FromStr;
+use itertools::{Itertools};
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Vec<Sue>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        util::parse_from_strings(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        report(find_id(input, &ticker_tape()))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        report(find_id_2(input, &ticker_tape()))
+    }
+
+    /// `size` aunts, at least two, one of them matching the ticker tape exactly and one
+    /// matching it with the ranges of part 2.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        let tape = ticker_tape().compounds.into_iter().sorted().collect_vec();
+        let size = Error::check_size(size, 2..)?;
+        let exact = rng.random_range(0..size);
+        let ranged = (exact + rng.random_range(1..size)) % size;
+        Ok(Some((0..size)
+            .map(|i| {
+                let compounds = rand::seq::index::sample(rng, tape.len(), 3).into_iter()
+                    .map(|c| {
+                        let (compound, value) = &tape[c];
+                        let value = match compound.as_str() {
+                            _ if i == exact => *value,
+                            "cats" | "trees" if i == ranged => value + rng.random_range(1..=3),
+                            "pomeranians" | "goldfish" if i == ranged => rng.random_range(0..*value),
+                            _ if i == ranged => *value,
+                            _ => rng.random_range(0..=10),
+                        };
+                        format!("{}: {}", compound, value)
+                    })
+                    .join(", ");
+                format!("Sue {}: {}", i + 1, compounds)
+            })
+            .join("\n")))
+    }
+}
+
+pub fn ticker_tape() -> Sue {
+    let compounds: Vec<(String, u32)> = vec![
+        ("children".to_string(), 3),
+        ("cats".to_string(), 7),
+        ("samoyeds".to_string(), 2),
+        ("pomeranians".to_string(), 3),
+        ("akitas".to_string(), 0),
+        ("vizslas".to_string(), 0),
+        ("goldfish".to_string(), 5),
+        ("trees".to_string(), 3),
+        ("cars".to_string(), 2),
+        ("perfumes".to_string(), 1),
+    ];
+    Sue {
+        id: 0,
+        compounds: compounds.into_iter().collect(),
+    }
+}
+
+fn report(id: Option<u32>) -> Result<Answer> {
+    id.map(Answer::from).ok_or_else(|| Error::unsolvable("no aunt Sue matches the ticker tape"))
+}
+
+pub fn find_id(sues: &[Sue], sue: &Sue) -> Option<u32> {
+    sues.iter().find(|s| {
+        for (k, v) in s.compounds.iter() {
+            if sue.compounds.get(k).unwrap() != v {
+                return false;
+            }
+        }
+        true
+    }).map(|s| s.id)
+}
+
+pub fn find_id_2(sues: &[Sue], sue: &Sue) -> Option<u32> {
+    sues.iter().find(|s| {
+        for (k, v) in s.compounds.iter() {
+            let computed_v = sue.compounds.get(k).unwrap();
+            let matches = match k.as_str() {
+                "cats" | "trees" => v > computed_v,
+                "pomeranians" | "goldfish" => v < computed_v,
+                _ => v == computed_v
+            };
+            if !matches {
+                return false;
+            }
+        }
+        true
+    }).map(|s| s.id)
+}
+
+#[derive(Clone, Debug)]
+pub struct Sue {
+    pub id: u32,
+    pub compounds: HashMap<String, u32>,
+}
+
+impl FromStr for Sue {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let words = s.split_whitespace()
+            .map(|s| s.trim_end_matches([',', ':']))
+            .collect_vec();
+        if words.len() % 2 != 0 || words.first() != Some(&"Sue") {
+            return Err(Error::parse(s, "expected `Sue <id>: <compound>: <n>, ...`"));
+        }
+        let mut compounds = HashMap::new();
+        let mut id = 0;
+        for (a, b) in words.into_iter().tuples() {
+            let n = b.parse::<u32>()?;
+            match a {
+                "Sue" => id = n,
+                _ if !ticker_tape().compounds.contains_key(a) =>
+                    return Err(Error::parse(a, "unknown compound")),
+                _ => { compounds.insert(a.to_string(), n); }
+            };
+        }
+
+        Ok(Sue { id, compounds })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day16::{find_id, find_id_2, ticker_tape, Day16};
+    use crate::solution::Solution;
+
+    #[test]
+    fn finds_matching_sue() {
+        let sues = Day16::parse("Sue 1: children: 1, cars: 2\nSue 2: cats: 7, trees: 3\nSue 3: cats: 8, goldfish: 4").unwrap();
+
+        assert_eq!(find_id(&sues, &ticker_tape()), Some(2));
+        assert_eq!(find_id_2(&sues, &ticker_tape()), Some(3));
+    }
+
+    #[test]
+    fn reports_no_matching_sue() {
+        let sues = Day16::parse("Sue 1: children: 1, cars: 3").unwrap();
+
+        assert_eq!(Day16::part1(&sues).unwrap_err().to_string(), "no answer: no aunt Sue matches the ticker tape");
+        assert!(Day16::part2(&sues).is_err());
+    }
+}
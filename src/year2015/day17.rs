@@ -0,0 +1,153 @@
+use itertools::Itertools;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Containers;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Containers { sizes: util::parse_from_strings(input)?, liters: 150 })
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(count_combinations(&input.sizes, input.liters))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(find_minimal_number_of_combinations(&input.sizes, input.liters))
+    }
+
+    fn configure(input: &mut Self::Input, name: &str, value: i64) -> Result<()> {
+        match name {
+            "liters" => input.liters = Error::check_setting(name, value, 0)?,
+            _ => return Err(Error::unknown_setting(name)),
+        }
+        Ok(())
+    }
+
+    /// `size` containers, at least four, four of which add up to 150 liters.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        let size = Error::check_size(size, 4..)?;
+        let mut cuts = rand::seq::index::sample(rng, 149, 3).into_iter().map(|c| c as u64 + 1).collect_vec();
+        cuts.sort();
+        let mut containers = [0, cuts[0], cuts[1], cuts[2], 150].windows(2).map(|w| w[1] - w[0]).collect_vec();
+        containers.extend((4..size).map(|_| rng.random_range(5..=50)));
+        containers.shuffle(rng);
+        Ok(Some(containers.iter().join("\n")))
+    }
+}
+
+/// The container sizes and the eggnog to store in them.
+#[derive(Debug)]
+pub struct Containers {
+    pub sizes: Vec<u64>,
+    pub liters: u64,
+}
+
+pub fn count_combinations(containers: &[u64], target: u64) -> usize {
+    let mut count = 0;
+    visit_combinations_with_sum(containers, target, None, &mut |_| {
+        count += 1;
+        false
+    });
+    count
+}
+
+pub fn find_minimal_number_of_combinations(containers: &[u64], target: u64) -> usize {
+    (0..=containers.len())
+        .map(|len| {
+            let mut count = 0;
+            visit_combinations_with_sum(containers, target, Some(len), &mut |_| {
+                count += 1;
+                false
+            });
+            count
+        })
+        .find(|count| *count > 0)
+        .unwrap_or(0)
+}
+
+/// Calls `visit` with the indices of every combination of `sizes` that adds up to `target`,
+/// optionally only those with exactly `len` elements. Branches that can no longer reach the
+/// target are pruned. Stops and returns `true` as soon as `visit` returns `true`.
+pub fn visit_combinations_with_sum(
+    sizes: &[u64],
+    target: u64,
+    len: Option<usize>,
+    visit: &mut impl FnMut(&[usize]) -> bool,
+) -> bool {
+    // Only compared with the target, so a sum too large for a `u64` can saturate
+    let mut remaining_sums = vec![0_u64; sizes.len() + 1];
+    for i in (0..sizes.len()).rev() {
+        remaining_sums[i] = remaining_sums[i + 1].saturating_add(sizes[i]);
+    }
+    search(sizes, &remaining_sums, 0, target, len, &mut vec![], visit)
+}
+
+fn search(
+    sizes: &[u64],
+    remaining_sums: &[u64],
+    start: usize,
+    target: u64,
+    len: Option<usize>,
+    chosen: &mut Vec<usize>,
+    visit: &mut impl FnMut(&[usize]) -> bool,
+) -> bool {
+    if target == 0 && len.is_none_or(|len| len == chosen.len()) && visit(chosen) {
+        return true;
+    }
+    if len.is_some_and(|len| len == chosen.len()) {
+        return false;
+    }
+    for i in start..sizes.len() {
+        if remaining_sums[i] < target {
+            break;
+        }
+        if sizes[i] > target {
+            continue;
+        }
+        chosen.push(i);
+        let found = search(sizes, remaining_sums, i + 1, target - sizes[i], len, chosen, visit);
+        chosen.pop();
+        if found {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day17::{count_combinations, find_minimal_number_of_combinations, Day17};
+    use crate::solution::{Answer, Solution};
+
+    #[test]
+    fn fills_example_containers() {
+        let containers = vec![20, 15, 10, 5, 5];
+
+        assert_eq!(count_combinations(&containers, 25), 4);
+        assert_eq!(find_minimal_number_of_combinations(&containers, 25), 3);
+    }
+
+    #[test]
+    fn stores_the_configured_amount() {
+        let mut containers = Day17::parse("20\n15\n10\n5\n5").unwrap();
+
+        Day17::configure(&mut containers, "liters", 25).unwrap();
+
+        assert_eq!(Day17::part1(&containers).unwrap(), Answer::Number(4));
+        assert_eq!(Day17::part2(&containers).unwrap(), Answer::Number(3));
+    }
+
+    #[test]
+    fn fills_huge_containers_without_overflowing() {
+        assert_eq!(count_combinations(&[u64::MAX, u64::MAX, 150], 150), 1);
+    }
+}
@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+use itertools::Itertools;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+pub type Light = (i32, i32);
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(&util::parse_strings(input))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(simulate_steps(&input.lights, input.steps, input.width, input.height, false))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(simulate_steps(&input.lights, input.steps, input.width, input.height, true))
+    }
+
+    fn configure(input: &mut Self::Input, name: &str, value: i64) -> Result<()> {
+        match name {
+            "steps" => input.steps = Error::check_setting(name, value, 0)?,
+            _ => return Err(Error::unknown_setting(name)),
+        }
+        Ok(())
+    }
+
+    /// A `size` by `size` grid, at least 1 by 1.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        let size = Error::check_size(size, 1..)?;
+        Ok(Some((0..size)
+            .map(|_| (0..size).map(|_| if rng.random_bool(0.5) { '#' } else { '.' }).collect::<String>())
+            .join("\n")))
+    }
+}
+
+#[derive(Debug)]
+pub struct Grid {
+    pub lights: HashSet<Light>,
+    pub width: i32,
+    pub height: i32,
+    /// How many steps both parts animate the grid for.
+    pub steps: usize,
+}
+
+pub fn simulate_steps(lights: &HashSet<Light>, steps: usize, max_x: i32, max_y: i32, stuck_corners: bool) -> usize {
+    let corners: HashSet<(i32, i32)> = if stuck_corners {
+        HashSet::from([(0, 0), (0, max_y - 1), (max_x - 1, 0), (max_x - 1, max_y - 1)])
+    } else {
+        HashSet::new()
+    };
+    let mut lights = lights
+        .union(&corners)
+        .cloned()
+        .collect();
+    for _ in 0..steps {
+        let set = step(lights, max_x, max_y);
+        lights = set.union(&corners)
+            .cloned()
+            .collect();
+    }
+    lights.len()
+}
+
+fn step(lights: HashSet<Light>, max_x: i32, max_y: i32) -> HashSet<Light> {
+    let potential_lights: HashSet<Light> = lights.iter()
+        .flat_map(neighbours)
+        .filter(|(x,y)| *x >= 0 && *x < max_x && *y >= 0 && *y < max_y)
+        .collect();
+    potential_lights.into_iter()
+        .filter(|light| on_next(light, lights.contains(light), &lights))
+        .collect()
+
+}
+
+fn on_next(light: &Light, on: bool, potential_lights: &HashSet<Light>) -> bool {
+    let actual_neighbours = neighbours(light)
+        .intersection(potential_lights)
+        .count();
+    match actual_neighbours {
+        3 => true,
+        2 if on => true,
+        _ => false,
+    }
+}
+
+fn neighbours(light: &Light) -> HashSet<Light> {
+    let mut lights = HashSet::new();
+    for x in [-1, 0, 1] {
+        for y in [-1, 0, 1] {
+            if x != 0 || y != 0 {
+                lights.insert((light.0 + x, light.1 + y));
+            }
+        }
+    }
+    lights
+}
+
+fn parse(input: &[String]) -> Result<Grid> {
+    let mut lights = HashSet::new();
+    let width = input.first().map(|row| row.len()).unwrap_or_default();
+    if width == 0 {
+        return Err(Error::parse("", "expected a grid of at least one light").at_line(1, ""));
+    }
+    for (y, row) in input.iter().enumerate() {
+        if row.len() != width {
+            return Err(Error::parse("", format!("expected a row of {} lights", width)).at_line(y + 1, row));
+        }
+        for (x, c) in row.chars().enumerate() {
+            match c {
+                '#' => { lights.insert((x as i32, y as i32)); }
+                '.' => {}
+                _ => return Err(Error::parse("", format!("unexpected `{}` in column {}", c, x + 1)).at_line(y + 1, row)),
+            }
+        }
+    }
+    Ok(Grid { lights, width: width as i32, height: input.len() as i32, steps: 100 })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day18::{simulate_steps, Day18};
+    use crate::solution::{Answer, Solution};
+
+    const EXAMPLE: &str = ".#.#.#
+...##.
+#....#
+..#...
+#.#..#
+####..";
+
+    #[test]
+    fn animates_example_grid() {
+        let grid = Day18::parse(EXAMPLE).unwrap();
+
+        assert_eq!((grid.width, grid.height), (6, 6));
+        assert_eq!(simulate_steps(&grid.lights, 4, grid.width, grid.height, false), 4);
+        assert_eq!(simulate_steps(&grid.lights, 5, grid.width, grid.height, true), 17);
+    }
+
+    #[test]
+    fn animates_for_the_configured_steps() {
+        let mut grid = Day18::parse(EXAMPLE).unwrap();
+
+        Day18::configure(&mut grid, "steps", 4).unwrap();
+        assert_eq!(Day18::part1(&grid).unwrap(), Answer::Number(4));
+        Day18::configure(&mut grid, "steps", 5).unwrap();
+        assert_eq!(Day18::part2(&grid).unwrap(), Answer::Number(17));
+    }
+
+    #[test]
+    fn reads_the_grid_size() {
+        let grid = Day18::parse("#..\n.#.").unwrap();
+
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(Day18::parse("#..\n.#").unwrap_err().to_string(), "line 2: expected a row of 3 lights\n    | .#");
+        assert_eq!(Day18::parse("").unwrap_err().to_string(), "line 1: expected a grid of at least one light");
+    }
+}
@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+use itertools::Itertools;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Vec<Transformation>, Molecule);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(&util::parse_strings(input))
+    }
+
+    fn part1((transformations, molecule): &Self::Input) -> Result<Answer> {
+        Answer::try_from(count_replacements(transformations, molecule))
+    }
+
+    fn part2((transformations, molecule): &Self::Input) -> Result<Answer> {
+        cheapest_match(transformations, molecule)
+            .map(Answer::try_from)
+            .ok_or_else(|| Error::unsolvable("the molecule cannot be made from `e`"))?
+    }
+
+    /// Replacements that each turn an atom into two, and a molecule made with `size` of them,
+    /// so that part 2 is exactly `size`, at least one.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        let atoms = ('A'..='J').map(String::from).collect_vec();
+        let mut rules = vec![];
+        for atom in atoms.iter().chain(["e".to_string()].iter()) {
+            for _ in 0..rng.random_range(1..=2) {
+                let replacement = vec![atoms[rng.random_range(0..atoms.len())].clone(), atoms[rng.random_range(0..atoms.len())].clone()];
+                rules.push((atom.clone(), replacement));
+            }
+        }
+        let mut molecule = vec!["e".to_string()];
+        for _ in 0..Error::check_size(size, 1..)? {
+            let position = rng.random_range(0..molecule.len());
+            let options = rules.iter().filter(|(atom, _)| *atom == molecule[position]).collect_vec();
+            let (_, replacement) = options[rng.random_range(0..options.len())];
+            molecule.splice(position..=position, replacement.clone());
+        }
+        let rules = rules.iter().map(|(atom, replacement)| format!("{} => {}", atom, replacement.concat())).join("\n");
+        Ok(Some(format!("{}\n\n{}", rules, molecule.concat())))
+    }
+}
+
+pub fn count_replacements(transformations: &[Transformation], molecule: &Molecule) -> usize {
+    let mut molecules = vec![];
+    for (target, replacement) in transformations {
+        let indexes = molecule.iter().positions(|c| c == target).collect_vec();
+        for index in indexes {
+            let mut new_molecule = molecule.clone();
+            new_molecule.splice(index..=index, replacement.clone()).count();
+            molecules.push(new_molecule);
+        }
+    }
+    let molecules = molecules.iter().unique().collect_vec();
+    molecules.len()
+}
+
+fn parse(input: &[String]) -> Result<(Vec<Transformation>, Molecule)> {
+    let transformations: Vec<Transformation> = input.iter()
+        .take_while(|line| !line.is_empty())
+        .enumerate()
+        .map(|(i, line)| match line.split(" => ").collect_tuple::<(&str, &str)>() {
+            Some((a, b)) if is_molecule(a) && is_molecule(b) => Ok((a.to_string(), separate(b.to_string()))),
+            _ => Err(Error::parse("", "expected a replacement like `H => HO`").at_line(i + 1, line)),
+        })
+        .collect::<Result<_>>()?;
+    let molecule = match input.last() {
+        Some(molecule) if is_molecule(molecule) && input.len() > transformations.len() + 1 => separate(molecule.clone()),
+        last => return Err(Error::parse("", "expected the medicine molecule after a blank line")
+            .at_line(input.len().max(1), last.map(|s| s.as_str()).unwrap_or_default())),
+    };
+    Ok((transformations, molecule))
+}
+
+fn is_molecule(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_uppercase() || c == 'e') && s.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+fn separate(molecule: String) -> Vec<String> {
+    molecule.chars()
+        .flat_map(|c| {
+            if c.is_ascii_uppercase() { vec![' ', c] } else { vec![c] }
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .map(|s| s.to_string())
+        .collect_vec()
+}
+
+#[derive(Clone, Hash, Ord, PartialOrd, Eq, PartialEq, Debug)]
+struct Match {
+    start: usize,
+    end: usize,
+    atom: String,
+}
+
+pub type Atom = String;
+pub type Molecule = Vec<Atom>;
+pub type Transformation = (Atom, Molecule);
+
+pub fn cheapest_match(transformations: &[Transformation], molecule: &Molecule) -> Option<usize> {
+    let mut matches: HashMap<Match, usize> = molecule.iter()
+        .enumerate()
+        .map(|(i, s)| (Match {
+            start: i,
+            end: i+1,
+            atom: s.clone(),
+        }, 0)).collect();
+    for match_length in 2..=molecule.len() {
+        let mut number_of_matches = matches.len();
+        loop {
+            for start in 0..molecule.len()-match_length+1 {
+                let end = start + match_length;
+                for (atom, seq) in transformations {
+                    if let Some(cheapest_split) = get_cheapest_split(start, end,seq.as_slice(), &matches) {
+                        let m = Match {
+                            start,
+                            end,
+                            atom: atom.clone(),
+                        };
+                        matches.insert(m, cheapest_split + 1);
+                    }
+                }
+            }
+            if number_of_matches == matches.len() {
+                break
+            } else {
+                number_of_matches = matches.len()
+            }
+        }
+    }
+
+    matches.get(&Match{
+        start: 0,
+        end: molecule.len(),
+        atom: "e".to_string(),
+    }).copied()
+}
+
+fn get_cheapest_split(start: usize, end: usize, seq: &[Atom], costs: &HashMap<Match, usize>) -> Option<usize> {
+    if seq.is_empty() && start == end {
+        return Some(0);
+    }
+    if seq.is_empty() {
+        return None;
+    }
+    if start >= end {
+        return None
+    }
+    let mut min: Option<usize> = None;
+    for first_end in start+1..=end {
+        let m = Match {
+            start,
+            end: first_end,
+            atom: seq[0].clone(),
+        };
+        if let Some(first_costs) = costs.get(&m) {
+            if let Some(c) =  get_cheapest_split(first_end, end, &seq[1..], costs) {
+                let total_costs = first_costs + c;
+                if min.is_none() || min.unwrap() > total_costs {
+                    min = Some(total_costs)
+                }
+            }
+        }
+    }
+    min
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day19::{cheapest_match, count_replacements, Day19};
+    use crate::solution::Solution;
+
+    const REPLACEMENTS: &str = "e => H\ne => O\nH => HO\nH => OH\nO => HH";
+
+    #[test]
+    fn counts_distinct_replacements() {
+        let (transformations, molecule) = Day19::parse(&format!("{}\n\nHOH", REPLACEMENTS)).unwrap();
+        assert_eq!(count_replacements(&transformations, &molecule), 4);
+
+        let (transformations, molecule) = Day19::parse(&format!("{}\n\nHOHOHO", REPLACEMENTS)).unwrap();
+        assert_eq!(count_replacements(&transformations, &molecule), 7);
+    }
+
+    #[test]
+    fn fabricates_molecule_in_fewest_steps() {
+        let (transformations, molecule) = Day19::parse(&format!("{}\n\nHOH", REPLACEMENTS)).unwrap();
+        assert_eq!(cheapest_match(&transformations, &molecule), Some(3));
+
+        let (transformations, molecule) = Day19::parse(&format!("{}\n\nHOHOHO", REPLACEMENTS)).unwrap();
+        assert_eq!(cheapest_match(&transformations, &molecule), Some(6));
+    }
+
+    #[test]
+    fn reports_molecules_that_cannot_be_made() {
+        let input = Day19::parse(&format!("{}\n\nHX", REPLACEMENTS)).unwrap();
+
+        assert_eq!(Day19::part2(&input).unwrap_err().to_string(), "no answer: the molecule cannot be made from `e`");
+    }
+
+    #[test]
+    fn rejects_a_missing_molecule() {
+        assert_eq!(Day19::parse("").unwrap_err().to_string(), "line 1: expected the medicine molecule after a blank line");
+        assert_eq!(Day19::parse(REPLACEMENTS).unwrap_err().to_string(), "line 5: expected the medicine molecule after a blank line\n    | O => HH");
+    }
+}
@@ -0,0 +1,108 @@
+use std::str::FromStr;
+use itertools::Itertools;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Package>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        util::parse_from_strings(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(amount_of_paper(input).ok_or_else(too_much)?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(amount_of_ribbon(input).ok_or_else(too_much)?)
+    }
+
+    /// `size` presents, at least one.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        Ok(Some((0..Error::check_size(size, 1..)?)
+            .map(|_| format!("{}x{}x{}", rng.random_range(1..=30), rng.random_range(1..=30), rng.random_range(1..=30)))
+            .join("\n")))
+    }
+}
+
+fn too_much() -> Error {
+    Error::unsolvable("the amount does not fit in a u64")
+}
+
+/// The square feet of wrapping paper, or `None` if it does not fit in a `u64`.
+pub fn amount_of_paper(packages: &[Package]) -> Option<u64> {
+    packages.iter().try_fold(0u64, |total, &Package { x, y, z }| {
+        let (x, y, z) = (u64::from(x), u64::from(y), u64::from(z));
+        let mut sides = [x * y, x * z, y * z];
+        sides.sort();
+        let paper = sides[0].checked_mul(3)?.checked_add(sides[1].checked_mul(2)?)?.checked_add(sides[2].checked_mul(2)?)?;
+        total.checked_add(paper)
+    })
+}
+
+/// The feet of ribbon, or `None` if it does not fit in a `u64`.
+pub fn amount_of_ribbon(packages: &[Package]) -> Option<u64> {
+    packages.iter().try_fold(0u64, |total, &Package { x, y, z }| {
+        let (a, b, c) = [x, y, z].into_iter()
+            .map(u64::from)
+            .sorted()
+            .collect_tuple()
+            .unwrap();
+        total.checked_add(2 * a + 2 * b)?.checked_add(a.checked_mul(b)?.checked_mul(c)?)
+    })
+}
+
+pub struct Package {
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
+}
+
+impl FromStr for Package {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (x, y, z) = s.split('x')
+            .map(|x| x.parse())
+            .collect_tuple()
+            .ok_or_else(|| Error::parse(s, "expected dimensions like `2x3x4`"))?;
+
+        Ok(Package { x: x?, y: y?, z: z? })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day2::{amount_of_paper, amount_of_ribbon, Day2};
+    use crate::solution::{Answer, Solution};
+
+    #[test]
+    fn wraps_example_presents() {
+        assert_eq!(amount_of_paper(&Day2::parse("2x3x4").unwrap()), Some(58));
+        assert_eq!(amount_of_paper(&Day2::parse("1x1x10").unwrap()), Some(43));
+        assert_eq!(amount_of_ribbon(&Day2::parse("2x3x4").unwrap()), Some(34));
+        assert_eq!(amount_of_ribbon(&Day2::parse("1x1x10").unwrap()), Some(14));
+    }
+
+    #[test]
+    fn wraps_huge_presents_without_overflowing() {
+        let packages = Day2::parse("99999x99999x99999").unwrap();
+        assert_eq!(Day2::part1(&packages).unwrap(), Answer::Number(7 * 99999 * 99999));
+        assert_eq!(Day2::part2(&packages).unwrap(), Answer::Number(4 * 99999 + 99999 * 99999 * 99999));
+
+        let packages = Day2::parse("4294967295x4294967295x4294967295").unwrap();
+        assert_eq!(Day2::part2(&packages).unwrap_err().to_string(), "no answer: the amount does not fit in a u64");
+    }
+
+    #[test]
+    fn rejects_malformed_dimensions() {
+        assert!(Day2::parse("2x3").is_err());
+        assert!(Day2::parse("2x3xfour").is_err());
+    }
+}
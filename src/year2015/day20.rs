@@ -0,0 +1,84 @@
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        util::parse_from_string(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(find_lucky_house(*input, 10, None).ok_or_else(too_far)?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(find_lucky_house(*input, 11, Some(50)).ok_or_else(too_far)?)
+    }
+
+    /// A number of presents between `size` and twice that, `size` at least one.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        let size = Error::check_size(size, 1..)?;
+        Ok(Some(rng.random_range(size..=2 * size).to_string()))
+    }
+}
+
+/// The sieve doubles until it finds the house, but stops at this many houses to bound its memory.
+const MAX_HOUSES: usize = 1 << 26;
+
+fn too_far() -> Error {
+    Error::unsolvable(format!("no house below {} gets that many presents", MAX_HOUSES))
+}
+
+pub fn find_lucky_house(target_presents: usize, present_multiplier: usize, house_limit: Option<usize>) -> Option<usize> {
+    // House n gets fewer than n * (1 + log2 n) times the multiplier, so skip sieving for a target beyond that
+    let max_presents = MAX_HOUSES * (1 + MAX_HOUSES.ilog2() as usize);
+    if target_presents / present_multiplier.max(1) > max_presents {
+        return None;
+    }
+    for sieve_size in (0..=MAX_HOUSES.ilog2()).map(|n| 1 << n) {
+        let mut houses = vec![present_multiplier; sieve_size];
+        for elf in 2..sieve_size {
+            for i in 1..house_limit.unwrap_or(sieve_size) {
+                if elf * i >= sieve_size {
+                    break;
+                }
+                houses[elf * i] = houses[elf * i].saturating_add(elf.saturating_mul(present_multiplier));
+            }
+        }
+        if let Some((idx,_item)) = houses.into_iter()
+            .enumerate()
+            .skip(1)
+            .find(|(_, ps)| *ps >= target_presents) {
+            return Some(idx)
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day20::{find_lucky_house, Day20};
+    use crate::solution::Solution;
+
+    #[test]
+    fn finds_first_house_with_enough_presents() {
+        assert_eq!(find_lucky_house(10, 10, None), Some(1));
+        assert_eq!(find_lucky_house(70, 10, None), Some(4));
+        assert_eq!(find_lucky_house(120, 10, None), Some(6));
+        assert_eq!(find_lucky_house(130, 10, None), Some(8));
+    }
+
+    #[test]
+    fn reports_houses_beyond_the_sieve() {
+        let presents = Day20::parse(&usize::MAX.to_string()).unwrap();
+
+        assert_eq!(Day20::part1(&presents).unwrap_err().to_string(), "no answer: no house below 67108864 gets that many presents");
+    }
+}
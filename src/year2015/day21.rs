@@ -0,0 +1,237 @@
+use std::str::FromStr;
+use itertools::Itertools;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Character;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        minimal_cost(&player(), input)
+            .map(Answer::from)
+            .ok_or_else(|| Error::unsolvable("the boss cannot be beaten"))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        maximal_cost_for_loss(&player(), input)
+            .map(Answer::from)
+            .ok_or_else(|| Error::unsolvable("the boss cannot be lost to"))
+    }
+
+    /// A boss with `size` hit points, between 50 and 150 so that some loadouts win and some lose.
+    /// The boss hits hard enough to outlast a player with just a dagger.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        let hit_points = Error::check_size(size, 50..=150)?;
+        Ok(Some(format!("Hit Points: {}\nDamage: {}\nArmor: {}", hit_points, rng.random_range(9..=12), rng.random_range(0..=3))))
+    }
+}
+
+fn player() -> Character {
+    Character {
+        hit_points: 100,
+        damage: 0,
+        armor: 0,
+    }
+}
+
+pub fn minimal_cost(player: &Character, boss: &Character) -> Option<i32> {
+    let loadouts = generate_loadouts();
+    loadouts.iter().filter(|loadout| {
+        let mut player = player.clone();
+        for item in *loadout {
+            player.equip(item);
+        }
+        player_wins(player, boss.clone())
+    })
+        .map(|loadout| loadout.iter().map(|l| l.cost).sum())
+        .min()
+}
+
+pub fn maximal_cost_for_loss(player: &Character, boss: &Character) -> Option<i32> {
+    let loadouts = generate_loadouts();
+    loadouts.iter().filter(|loadout| {
+        let mut player = player.clone();
+        for item in *loadout {
+            player.equip(item);
+        }
+        !player_wins(player, boss.clone())
+    })
+        .map(|loadout| loadout.iter().map(|l| l.cost).sum())
+        .max()
+}
+
+fn generate_loadouts() -> Vec<Vec<Item>> {
+    let weapons = get_weapons();
+    let armor = get_armor();
+    let rings = get_rings();
+    let mut loadouts = vec![];
+    for weapons_selection in select_items_combinations(&weapons, 1, 1) {
+        for armor_selection in select_items_combinations(&armor, 0, 1) {
+            for ring_selection in select_items_combinations(&rings, 0, 2) {
+                let mut loadout = vec![];
+                loadout.extend(weapons_selection.iter().cloned());
+                loadout.extend(armor_selection.iter().cloned());
+                loadout.extend(ring_selection.iter().cloned());
+                loadouts.push(loadout)
+            }
+        }
+    }
+    loadouts
+}
+
+fn select_items_combinations(items: &[Item], min: usize, max: usize) -> Vec<Vec<Item>> {
+    (min..=max)
+        .flat_map(|size| items.iter().cloned().combinations(size))
+        .collect()
+}
+
+#[derive(Clone)]
+struct Item {
+    #[allow(dead_code)]
+    name: String,
+    cost: i32,
+    damage: i32,
+    armor: i32,
+}
+
+fn get_weapons() -> Vec<Item> {
+    vec![
+        Item { name: String::from("Dagger"), cost: 8, damage: 4, armor: 0 },
+        Item { name: String::from("Shortsword"), cost: 10, damage: 5, armor: 0 },
+        Item { name: String::from("Warhammer"), cost: 25, damage: 6, armor: 0 },
+        Item { name: String::from("Longsword"), cost: 40, damage: 7, armor: 0 },
+        Item { name: String::from("Greataxe"), cost: 74, damage: 8, armor: 0 },
+    ]
+}
+
+fn get_armor() -> Vec<Item> {
+    vec![
+        Item { name: String::from("Leather"), cost: 13, damage: 0, armor: 1 },
+        Item { name: String::from("Chainmail"), cost: 31, damage: 0, armor: 2 },
+        Item { name: String::from("Splintmail"), cost: 53, damage: 0, armor: 3 },
+        Item { name: String::from("Bandedmail"), cost: 75, damage: 0, armor: 4 },
+        Item { name: String::from("Platemail"), cost: 102, damage: 0, armor: 5 },
+    ]
+}
+
+fn get_rings() -> Vec<Item> {
+    vec![
+        Item { name: String::from("Damage +1"), cost: 25, damage: 1, armor: 0 },
+        Item { name: String::from("Damage +2"), cost: 50, damage: 2, armor: 0 },
+        Item { name: String::from("Damage +3"), cost: 100, damage: 3, armor: 0 },
+        Item { name: String::from("Defense +1"), cost: 20, damage: 0, armor: 1 },
+        Item { name: String::from("Defense +2"), cost: 40, damage: 0, armor: 2 },
+        Item { name: String::from("Defense +3"), cost: 80, damage: 0, armor: 3 },
+    ]
+}
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Character {
+    pub hit_points: i32,
+    pub damage: i32,
+    pub armor: i32,
+}
+
+impl Character {
+    fn equip(&mut self, item: &Item) {
+        self.damage += item.damage;
+        self.armor += item.armor;
+    }
+
+    fn calculate_damage(&self, other: &Character) -> i32 {
+        (self.damage - other.armor).max(0)
+    }
+}
+
+pub fn player_wins(mut player: Character, mut boss: Character) -> bool {
+    loop {
+        // Player's turn
+        let damage = player.calculate_damage(&boss);
+        if damage == 0 {
+            return false;
+        }
+        boss.hit_points -= damage;
+        if boss.hit_points <= 0 {
+            // Player wins
+            return true;
+        }
+
+        // Boss's turn
+        let boss_damage = boss.calculate_damage(&player);
+        player.hit_points -= boss_damage;
+        if player.hit_points <= 0 {
+            // Boss wins
+            return false;
+        }
+    }
+}
+
+impl FromStr for Character {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (mut hit_points, mut damage, mut armor) = (None, None, 0);
+        for (i, line) in s.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let Some((stat, value)) = line.split_once(": ") else {
+                return Err(Error::parse("", "expected `<stat>: <value>`").at_line(i + 1, line));
+            };
+            let value = value.trim().parse::<i32>().map_err(|e| Error::from(e).at_line(i + 1, line))?;
+            // Fights only make sense for living characters that do not heal their opponent
+            match stat {
+                "Hit Points" if value <= 0 => return Err(Error::parse("", "expected at least 1 hit point").at_line(i + 1, line)),
+                "Damage" | "Armor" if value < 0 => return Err(Error::parse("", "expected a stat of at least 0").at_line(i + 1, line)),
+                "Hit Points" => hit_points = Some(value),
+                "Damage" => damage = Some(value),
+                "Armor" => armor = value,
+                _ => return Err(Error::parse(stat, "unknown stat").at_line(i + 1, line)),
+            }
+        }
+        match (hit_points, damage) {
+            (Some(hit_points), Some(damage)) => Ok(Character { hit_points, damage, armor }),
+            _ => Err(Error::parse(s.trim(), "expected at least hit points and damage")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day21::{player_wins, Character, Day21};
+    use crate::solution::Solution;
+
+    #[test]
+    fn fights_example_boss() {
+        let player = Character { hit_points: 8, damage: 5, armor: 5 };
+        let boss = Day21::parse("Hit Points: 12\nDamage: 7\nArmor: 2").unwrap();
+
+        assert!(player_wins(player.clone(), boss.clone()));
+        assert!(!player_wins(Character { hit_points: 2, ..player }, boss));
+    }
+
+    #[test]
+    fn rejects_dead_bosses_and_negative_stats() {
+        assert_eq!(
+            Day21::parse("Hit Points: -2147483648\nDamage: 7").unwrap_err().to_string(),
+            "line 1: expected at least 1 hit point\n    | Hit Points: -2147483648",
+        );
+        assert!(Day21::parse("Hit Points: 0\nDamage: 7").is_err());
+        assert!(Day21::parse("Hit Points: 12\nDamage: 7\nArmor: -1").is_err());
+        assert!(Day21::parse("Hit Points: 12\nDamage: 0\nArmor: 0").is_ok());
+    }
+
+    #[test]
+    fn reports_bosses_that_always_win_or_always_lose() {
+        let unbeatable = Day21::parse("Hit Points: 1000\nDamage: 100\nArmor: 0").unwrap();
+        assert_eq!(Day21::part1(&unbeatable).unwrap_err().to_string(), "no answer: the boss cannot be beaten");
+
+        let harmless = Day21::parse("Hit Points: 1\nDamage: 0\nArmor: 0").unwrap();
+        assert_eq!(Day21::part2(&harmless).unwrap_err().to_string(), "no answer: the boss cannot be lost to");
+    }
+}
@@ -0,0 +1,339 @@
+use std::fmt::{Display, Formatter};
+use itertools::Itertools;
+use pathfinding::prelude::dijkstra;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::year2015::day21::Character;
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+
+/// The search for the cheapest win grows quickly with the boss's hit points, it takes about a
+/// second in a release build for a boss with this many.
+const MAX_BOSS_HIT_POINTS: i32 = 200;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Fight;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Fight { player: player(), mana: 500, boss: input.parse()? })
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        fight(input, false)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        fight(input, true)
+    }
+
+    /// `hit_points` and `mana` set what the player starts with, at most 65535 each.
+    fn configure(input: &mut Self::Input, name: &str, value: i64) -> Result<()> {
+        match name {
+            "hit_points" => input.player.hit_points = Error::check_setting::<u16>(name, value, 1)?.into(),
+            "mana" => input.mana = Error::check_setting::<u16>(name, value, 0)?.into(),
+            _ => return Err(Error::unknown_setting(name)),
+        }
+        Ok(())
+    }
+
+    /// A boss with `size` hit points, between 1 and 70 so that it can be beaten in hard mode.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        let hit_points = Error::check_size(size, 1..=70)?;
+        Ok(Some(format!("Hit Points: {}\nDamage: {}", hit_points, rng.random_range(5..=9))))
+    }
+}
+
+/// The player with their starting mana, and the boss from the puzzle input.
+#[derive(Debug)]
+pub struct Fight {
+    pub player: Character,
+    pub mana: i32,
+    pub boss: Character,
+}
+
+/// The cheapest win of the fight, for bosses the search can handle.
+fn fight(fight: &Fight, hard: bool) -> Result<Answer> {
+    if fight.boss.hit_points > MAX_BOSS_HIT_POINTS {
+        let message = format!("cannot search for a win against a boss with more than {} hit points", MAX_BOSS_HIT_POINTS);
+        return Err(Error::unsolvable(message));
+    }
+    report(cheapest_win(&fight.player, fight.mana, &fight.boss, hard))
+}
+
+/// The mana spent, explained by the spells cast, e.g. `226 (Poison, Magic Missile)`.
+fn report(victory: Option<Victory>) -> Result<Answer> {
+    match victory {
+        Some(victory) => Ok(Answer::Explained {
+            number: victory.mana_spent.into(),
+            detail: victory.spells.iter().join(", "),
+        }),
+        None => Err(Error::unsolvable("the boss cannot be beaten")),
+    }
+}
+
+fn player() -> Character {
+    Character {
+        hit_points: 50,
+        damage: 0,
+        armor: 0,
+    }
+}
+
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub enum Spell {
+    MagicMissile,
+    Drain,
+    Shield,
+    Poison,
+    Recharge,
+}
+
+impl Spell {
+    const ALL: [Spell; 5] = [Spell::MagicMissile, Spell::Drain, Spell::Shield, Spell::Poison, Spell::Recharge];
+
+    fn cost(&self) -> i32 {
+        match self {
+            Spell::MagicMissile => 53,
+            Spell::Drain => 73,
+            Spell::Shield => 113,
+            Spell::Poison => 173,
+            Spell::Recharge => 229,
+        }
+    }
+
+    /// Slot of the spell's timer in `State::timers`, if the spell starts an effect.
+    fn effect(&self) -> Option<usize> {
+        match self {
+            Spell::Shield => Some(0),
+            Spell::Poison => Some(1),
+            Spell::Recharge => Some(2),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Spell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Spell::MagicMissile => "Magic Missile",
+            Spell::Drain => "Drain",
+            Spell::Shield => "Shield",
+            Spell::Poison => "Poison",
+            Spell::Recharge => "Recharge",
+        };
+        f.pad(name)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Victory {
+    pub mana_spent: u32,
+    pub spells: Vec<Spell>,
+}
+
+impl Display for Victory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.mana_spent, self.spells.iter().join(", "))
+    }
+}
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+struct State {
+    player: Character,
+    mana: i32,
+    boss: Character,
+    timers: [u8; 3],
+}
+
+impl State {
+    fn apply_effects(&mut self) {
+        let [shield, poison, recharge] = &mut self.timers;
+        if *shield > 0 {
+            *shield -= 1;
+        }
+        self.player.armor = if *shield > 0 { 7 } else { 0 };
+        if *poison > 0 {
+            *poison -= 1;
+            self.boss.hit_points -= 3;
+        }
+        if *recharge > 0 {
+            *recharge -= 1;
+            self.mana += 101;
+        }
+    }
+
+    fn cast(&mut self, spell: Spell) {
+        self.mana -= spell.cost();
+        match spell {
+            Spell::MagicMissile => self.boss.hit_points -= 4,
+            Spell::Drain => {
+                self.boss.hit_points -= 2;
+                self.player.hit_points += 2;
+            }
+            Spell::Shield => {
+                self.timers[0] = 6;
+                self.player.armor = 7;
+            }
+            Spell::Poison => self.timers[1] = 6,
+            Spell::Recharge => self.timers[2] = 5,
+        }
+    }
+
+    fn boss_defeated(&self) -> bool {
+        self.boss.hit_points <= 0
+    }
+
+    /// All states reachable by playing one round (the player's turn and the boss's turn),
+    /// together with the spell cast in it, if the boss survived the effects long enough.
+    fn rounds(&self, hard: bool) -> Vec<(State, Option<Spell>)> {
+        let mut state = self.clone();
+        if hard {
+            state.player.hit_points -= 1;
+            if state.player.hit_points <= 0 {
+                return vec![];
+            }
+        }
+        state.apply_effects();
+        if state.boss_defeated() {
+            return vec![(state, None)];
+        }
+
+        let mut rounds = vec![];
+        for spell in Spell::ALL {
+            let active = spell.effect().map(|i| state.timers[i] > 0).unwrap_or(false);
+            if spell.cost() > state.mana || active {
+                continue;
+            }
+            let mut next = state.clone();
+            next.cast(spell);
+            if !next.boss_defeated() {
+                next.apply_effects();
+            }
+            if !next.boss_defeated() {
+                next.player.hit_points -= (next.boss.damage - next.player.armor).max(1);
+                if next.player.hit_points <= 0 {
+                    continue;
+                }
+            }
+            rounds.push((next, Some(spell)));
+        }
+        rounds
+    }
+
+    fn successors(&self, hard: bool) -> Vec<(State, u32)> {
+        self.rounds(hard).into_iter()
+            .map(|(state, spell)| (state, spell.map(|s| s.cost() as u32).unwrap_or(0)))
+            .collect()
+    }
+
+    /// The spell cast to get from this state to `next` in one round.
+    fn spell_towards(&self, next: &State, hard: bool) -> Option<Spell> {
+        self.rounds(hard).into_iter().find(|(state, _)| state == next).and_then(|(_, spell)| spell)
+    }
+}
+
+/// Finds the least amount of mana the player can spend and still win, along with the
+/// spells to cast. In hard mode the player loses one hit point at the start of each of their turns.
+pub fn cheapest_win(player: &Character, mana: i32, boss: &Character, hard: bool) -> Option<Victory> {
+    let start = State {
+        player: player.clone(),
+        mana,
+        boss: boss.clone(),
+        timers: [0; 3],
+    };
+    let (states, mana_spent) = dijkstra(&start, |s| s.successors(hard), |s| s.boss_defeated())?;
+    let spells = states.iter().tuple_windows().filter_map(|(a, b)| a.spell_towards(b, hard)).collect();
+    Some(Victory { mana_spent, spells })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day21::Character;
+    use crate::year2015::day22::{cheapest_win, Day22, Spell};
+    use crate::solution::{Answer, Solution};
+
+    fn boss(hit_points: i32) -> Character {
+        Character { hit_points, damage: 8, armor: 0 }
+    }
+
+    fn player() -> Character {
+        Character { hit_points: 10, damage: 0, armor: 0 }
+    }
+
+    #[test]
+    fn wins_first_example() {
+        let victory = cheapest_win(&player(), 250, &boss(13), false).unwrap();
+
+        assert_eq!(victory.spells, vec![Spell::Poison, Spell::MagicMissile]);
+        assert_eq!(victory.mana_spent, 226);
+    }
+
+    #[test]
+    fn wins_second_example() {
+        let victory = cheapest_win(&player(), 250, &boss(14), false).unwrap();
+
+        assert_eq!(victory.spells, vec![Spell::Recharge, Spell::Shield, Spell::Drain, Spell::Poison, Spell::MagicMissile]);
+        assert_eq!(victory.mana_spent, 641);
+        assert_eq!(victory.to_string(), "641 (Recharge, Shield, Drain, Poison, Magic Missile)");
+    }
+
+    #[test]
+    fn loses_in_hard_mode() {
+        assert_eq!(cheapest_win(&player(), 250, &boss(13), true), None);
+    }
+
+    #[test]
+    fn answers_with_the_mana_spent_and_the_spells() {
+        let boss = Day22::parse("Hit Points: 13\nDamage: 8").unwrap();
+
+        let answer = Day22::part1(&boss).unwrap();
+
+        assert_eq!(answer.unexplained(), Answer::Number(212));
+        assert_eq!(answer.to_string(), "212 (Magic Missile, Magic Missile, Magic Missile, Magic Missile)");
+    }
+
+    #[test]
+    fn fights_with_the_configured_player() {
+        let mut fight = Day22::parse("Hit Points: 14\nDamage: 8").unwrap();
+
+        Day22::configure(&mut fight, "hit_points", 10).unwrap();
+        Day22::configure(&mut fight, "mana", 250).unwrap();
+
+        assert_eq!(Day22::part1(&fight).unwrap().unexplained(), Answer::Number(641));
+        assert!(Day22::configure(&mut fight, "hit_points", 0).is_err());
+        assert!(Day22::configure(&mut fight, "mana", 65536).is_err());
+    }
+
+    #[test]
+    fn rejects_bosses_with_extreme_stats() {
+        assert_eq!(
+            Day22::parse("Hit Points: 13\nDamage: -2147483648").unwrap_err().to_string(),
+            "line 2: expected a stat of at least 0\n    | Damage: -2147483648",
+        );
+        assert!(Day22::parse("Hit Points: -2147483648\nDamage: 8").is_err());
+        assert!(Day22::part1(&Day22::parse("Hit Points: 13\nDamage: 2147483647").unwrap()).is_err());
+    }
+
+    #[test]
+    fn rejects_bosses_with_too_many_hit_points() {
+        let fight = Day22::parse("Damage: 1\nHit Points: 2000").unwrap();
+
+        assert_eq!(
+            Day22::part1(&fight).unwrap_err().to_string(),
+            "no answer: cannot search for a win against a boss with more than 200 hit points",
+        );
+        assert!(Day22::part2(&fight).is_err());
+    }
+
+    #[test]
+    fn reports_unbeatable_boss() {
+        let boss = Day22::parse("Hit Points: 200\nDamage: 30").unwrap();
+
+        let error = Day22::part1(&boss).unwrap_err();
+
+        assert_eq!(error.to_string(), "no answer: the boss cannot be beaten");
+    }
+}
@@ -0,0 +1,278 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use itertools::Itertools;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::year2015::day23::Instruction::{Half, Increment, Jump, JumpIfEven, JumpIfOne, Triple};
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+const STEP_LIMIT: usize = 10_000_000;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        util::parse_from_strings(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(register_b_after(input, 0).map_err(Error::unsolvable)?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(register_b_after(input, 1).map_err(Error::unsolvable)?)
+    }
+
+    /// A program like the puzzle's of `size` instructions, at least 12: the start value for each
+    /// part, computed in about half of them, followed by a loop counting the Collatz steps from it.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        // The two jumps around the setups and the loop take 10 instructions
+        let setups = Error::check_size(size, 12..)? - 10;
+        let mut setup = |length: usize| {
+            let mut triples = 0;
+            let mut lines = vec!["inc a"];
+            for _ in 1..length {
+                // Few enough triples for the Collatz sequence to stay within a `u64`
+                if triples < 20 && rng.random_bool(0.4) {
+                    triples += 1;
+                    lines.push("tpl a");
+                } else {
+                    lines.push("inc a");
+                }
+            }
+            lines
+        };
+        let (first, second) = (setup(setups / 2), setup(setups - setups / 2));
+        let program = [
+            vec![format!("jio a, +{}", first.len() + 2)],
+            first.iter().map(|l| l.to_string()).collect(),
+            vec![format!("jmp +{}", second.len() + 1)],
+            second.iter().map(|l| l.to_string()).collect(),
+            ["jio a, +8", "inc b", "jie a, +4", "tpl a", "inc a", "jmp +2", "hlf a", "jmp -7"].map(String::from).to_vec(),
+        ];
+        Ok(Some(program.concat().join("\n")))
+    }
+}
+
+pub fn register_b_after(program: &[Instruction], a: u64) -> std::result::Result<u64, StepLimitExceeded> {
+    let mut machine = Machine::new(program, [a, 0]).with_step_limit(STEP_LIMIT);
+    Ok(machine.run()?[Register::B as usize])
+}
+
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub enum Register {
+    A,
+    B,
+}
+
+impl FromStr for Register {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "a" => Ok(Register::A),
+            "b" => Ok(Register::B),
+            _ => Err(Error::parse(s, "unknown register")),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub enum Instruction {
+    Half(Register),
+    Triple(Register),
+    Increment(Register),
+    Jump(i64),
+    JumpIfEven(Register, i64),
+    JumpIfOne(Register, i64),
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match &s.split_whitespace().collect_vec()[..] {
+            ["hlf", r] => Half(r.parse()?),
+            ["tpl", r] => Triple(r.parse()?),
+            ["inc", r] => Increment(r.parse()?),
+            ["jmp", offset] => Jump(offset.parse()?),
+            ["jie", r, offset] => JumpIfEven(conditional_register(r)?, offset.parse()?),
+            ["jio", r, offset] => JumpIfOne(conditional_register(r)?, offset.parse()?),
+            _ => return Err(Error::parse(s, "unknown instruction")),
+        })
+    }
+}
+
+/// The register of a conditional jump, which is followed by a comma as in `jie a, +4`.
+fn conditional_register(s: &str) -> Result<Register> {
+    s.strip_suffix(',')
+        .ok_or_else(|| Error::parse(s, "expected a comma after the register"))?
+        .parse()
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TraceEntry {
+    pub pc: usize,
+    pub instruction: Instruction,
+    pub registers: [u64; 2],
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct StepLimitExceeded {
+    pub pc: i64,
+    pub registers: [u64; 2],
+}
+
+impl Display for StepLimitExceeded {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the program did not halt, stopped at instruction {} with a = {} and b = {}", self.pc, self.registers[0], self.registers[1])
+    }
+}
+
+pub struct Machine<'a> {
+    program: &'a [Instruction],
+    pub pc: i64,
+    pub registers: [u64; 2],
+    pub steps: usize,
+    step_limit: Option<usize>,
+    trace: Option<Vec<TraceEntry>>,
+}
+
+impl<'a> Machine<'a> {
+    pub fn new(program: &'a [Instruction], registers: [u64; 2]) -> Self {
+        Machine { program, pc: 0, registers, steps: 0, step_limit: None, trace: None }
+    }
+
+    pub fn with_step_limit(mut self, step_limit: usize) -> Self {
+        self.step_limit = Some(step_limit);
+        self
+    }
+
+    /// Records every executed instruction together with the registers before it ran.
+    pub fn with_trace(mut self) -> Self {
+        self.trace = Some(vec![]);
+        self
+    }
+
+    pub fn trace(&self) -> &[TraceEntry] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+
+    pub fn halted(&self) -> bool {
+        self.pc < 0 || self.pc >= self.program.len() as i64
+    }
+
+    /// Executes a single instruction. Returns `false` if the machine has already halted.
+    /// Registers wrap around on overflow.
+    pub fn step(&mut self) -> bool {
+        if self.halted() {
+            return false;
+        }
+        let pc = self.pc as usize;
+        let instruction = self.program[pc];
+        if let Some(trace) = &mut self.trace {
+            trace.push(TraceEntry { pc, instruction, registers: self.registers });
+        }
+        let offset = match instruction {
+            Half(r) => {
+                self.registers[r as usize] /= 2;
+                1
+            }
+            Triple(r) => {
+                self.registers[r as usize] = self.registers[r as usize].wrapping_mul(3);
+                1
+            }
+            Increment(r) => {
+                self.registers[r as usize] = self.registers[r as usize].wrapping_add(1);
+                1
+            }
+            Jump(offset) => offset,
+            JumpIfEven(r, offset) if self.registers[r as usize].is_multiple_of(2) => offset,
+            JumpIfOne(r, offset) if self.registers[r as usize] == 1 => offset,
+            JumpIfEven(..) | JumpIfOne(..) => 1,
+        };
+        self.pc = self.pc.saturating_add(offset);
+        self.steps += 1;
+        true
+    }
+
+    /// Runs until the program counter leaves the program, and returns the final registers.
+    pub fn run(&mut self) -> std::result::Result<[u64; 2], StepLimitExceeded> {
+        while !self.halted() {
+            if self.step_limit.is_some_and(|limit| self.steps >= limit) {
+                return Err(StepLimitExceeded { pc: self.pc, registers: self.registers });
+            }
+            self.step();
+        }
+        Ok(self.registers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day23::{Day23, Instruction, Machine, Register, StepLimitExceeded};
+    use crate::solution::Solution;
+    use crate::util;
+
+    #[test]
+    fn runs_example() {
+        let program: Vec<Instruction> = util::parse_from_strings("inc a\njio a, +2\ntpl a\ninc a").unwrap();
+        let mut machine = Machine::new(&program, [0, 0]).with_trace();
+
+        assert_eq!(machine.run(), Ok([2, 0]));
+        assert_eq!(machine.trace().iter().map(|t| t.pc).collect::<Vec<_>>(), vec![0, 1, 3]);
+        assert_eq!(machine.trace()[2].registers, [1, 0]);
+    }
+
+    #[test]
+    fn stops_at_step_limit() {
+        let program = vec![Instruction::Increment(Register::B), Instruction::Jump(-1)];
+        let mut machine = Machine::new(&program, [0, 0]).with_step_limit(5);
+
+        assert_eq!(machine.run(), Err(StepLimitExceeded { pc: 1, registers: [0, 3] }));
+    }
+
+    #[test]
+    fn wraps_around_on_overflow() {
+        let program: Vec<Instruction> = util::parse_from_strings("tpl a\ninc b\njmp +9223372036854775807").unwrap();
+        let mut machine = Machine::new(&program, [u64::MAX / 2, u64::MAX]);
+
+        assert_eq!(machine.run(), Ok([(u64::MAX / 2).wrapping_mul(3), 0]));
+    }
+
+    #[test]
+    fn reports_registers_beyond_i64() {
+        let program = Day23::parse(&format!("inc b{}", "\ntpl b".repeat(40))).unwrap();
+
+        assert_eq!(Day23::part1(&program).unwrap_err().to_string(), "no answer: the answer 12157665459056928801 does not fit in an i64");
+    }
+
+    #[test]
+    fn reports_programs_that_do_not_halt() {
+        let program = Day23::parse("inc b\njmp -1").unwrap();
+
+        let error = Day23::part1(&program).unwrap_err();
+
+        assert_eq!(error.to_string(), "no answer: the program did not halt, stopped at instruction 0 with a = 0 and b = 5000000");
+    }
+
+    #[test]
+    fn rejects_unknown_instructions() {
+        assert!("inc c".parse::<Instruction>().is_err());
+        assert!("jmp a".parse::<Instruction>().is_err());
+        assert!("nop".parse::<Instruction>().is_err());
+    }
+
+    #[test]
+    fn rejects_misplaced_commas() {
+        assert_eq!("inc a,".parse::<Instruction>().unwrap_err().to_string(), "unknown register\n    | a,");
+        assert_eq!("jie a +4".parse::<Instruction>().unwrap_err().to_string(), "expected a comma after the register\n    | a");
+        assert!("jio a,, +1".parse::<Instruction>().is_err());
+        assert_eq!("jio b, -2".parse::<Instruction>().unwrap(), Instruction::JumpIfOne(Register::B, -2));
+    }
+}
@@ -0,0 +1,149 @@
+use itertools::Itertools;
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+
+use crate::year2015::day17::visit_combinations_with_sum;
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        util::parse_from_strings(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        balance(input, 3)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        balance(input, 4)
+    }
+
+    /// `size` packages, made from 12 groups of equal weight so that they can be split into 3 and
+    /// into 4. Each group has at least one package and at most one per unit of weight.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        const GROUP_WEIGHT: usize = 120;
+        let size = Error::check_size(size, 12..=12 * GROUP_WEIGHT)?;
+        let mut packages = vec![];
+        for group in 0..12 {
+            let count = size / 12 + usize::from(group < size % 12);
+            let mut cuts = rand::seq::index::sample(rng, GROUP_WEIGHT - 1, count - 1).into_iter().map(|c| c + 1).collect_vec();
+            cuts.sort();
+            let bounds = [vec![0], cuts, vec![GROUP_WEIGHT]].concat();
+            packages.extend(bounds.windows(2).map(|w| w[1] - w[0]));
+        }
+        packages.shuffle(rng);
+        Ok(Some(packages.iter().join("\n")))
+    }
+}
+
+fn balance(packages: &[u64], groups: u64) -> Result<Answer> {
+    let quantum_entanglement = ideal_quantum_entanglement(packages, groups)?;
+    i64::try_from(quantum_entanglement)
+        .map(Answer::from)
+        .map_err(|_| Error::unsolvable(format!("the quantum entanglement {} does not fit in an i64", quantum_entanglement)))
+}
+
+/// Finds the smallest group of packages for the passenger compartment, preferring the lowest
+/// quantum entanglement, such that the other packages still split into equally heavy groups.
+pub fn ideal_quantum_entanglement(packages: &[u64], groups: u64) -> Result<u64> {
+    let unbalanced = || Error::unsolvable(format!("the packages cannot be split into {} equally heavy groups", groups));
+    let total = packages.iter()
+        .try_fold(0u64, |total, p| total.checked_add(*p))
+        .ok_or_else(|| Error::unsolvable("the total weight of the packages does not fit in a u64"))?;
+    if groups == 0 || !total.is_multiple_of(groups) {
+        return Err(unbalanced());
+    }
+    let target = total / groups;
+
+    for len in 1..=packages.len() {
+        let mut candidates = vec![];
+        visit_combinations_with_sum(packages, target, Some(len), &mut |group| {
+            candidates.push(group.to_vec());
+            false
+        });
+        // Groups whose quantum entanglement overflows go last
+        candidates.sort_by_key(|group| {
+            let quantum_entanglement = quantum_entanglement(packages, group);
+            (quantum_entanglement.is_none(), quantum_entanglement)
+        });
+        for group in candidates {
+            if can_split(&without(packages, &group), groups - 1, target) {
+                return quantum_entanglement(packages, &group)
+                    .ok_or_else(|| Error::unsolvable("the quantum entanglement does not fit in a u64"));
+            }
+        }
+    }
+    Err(unbalanced())
+}
+
+fn can_split(packages: &[u64], groups: u64, target: u64) -> bool {
+    if groups <= 1 {
+        return packages.iter().sum::<u64>() == target * groups;
+    }
+    visit_combinations_with_sum(packages, target, None, &mut |group| {
+        can_split(&without(packages, group), groups - 1, target)
+    })
+}
+
+/// The product of the weights in `group`, or `None` if it overflows.
+fn quantum_entanglement(packages: &[u64], group: &[usize]) -> Option<u64> {
+    group.iter().try_fold(1u64, |product, i| product.checked_mul(packages[*i]))
+}
+
+fn without(packages: &[u64], group: &[usize]) -> Vec<u64> {
+    packages.iter()
+        .enumerate()
+        .filter(|(i, _)| !group.contains(i))
+        .map(|(_, p)| *p)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use crate::year2015::day24::{ideal_quantum_entanglement, Day24};
+    use crate::solution::{Answer, Solution};
+
+    #[test]
+    fn balances_example() {
+        let packages = vec![1, 2, 3, 4, 5, 7, 8, 9, 10, 11];
+
+        assert_eq!(ideal_quantum_entanglement(&packages, 3).unwrap(), 99);
+        assert_eq!(ideal_quantum_entanglement(&packages, 4).unwrap(), 44);
+    }
+
+    #[test]
+    fn requires_an_even_split_of_the_rest() {
+        assert!(ideal_quantum_entanglement(&[4, 7, 8, 11, 13, 14], 3).is_err());
+        assert_eq!(ideal_quantum_entanglement(&[5, 4, 1, 2, 3], 3).unwrap(), 5);
+    }
+
+    #[test]
+    fn reports_unbalanced_packages() {
+        let packages = Day24::parse("1\n2\n3\n4").unwrap();
+
+        assert_eq!(Day24::part1(&packages).unwrap_err().to_string(), "no answer: the packages cannot be split into 3 equally heavy groups");
+        assert!(Day24::part2(&Day24::parse("").unwrap()).is_err());
+    }
+
+    #[test]
+    fn reports_quantum_entanglements_that_overflow() {
+        let parse = |weights: &[u64]| Day24::parse(&weights.iter().join("\n")).unwrap();
+
+        let packages = parse(&[1 << 40; 6]);
+        assert_eq!(Day24::part1(&packages).unwrap_err().to_string(), "no answer: the quantum entanglement does not fit in a u64");
+
+        let packages = parse(&[1 << 31, 1 << 32, 1 << 31, 1 << 32, 1 << 31, 1 << 32]);
+        assert_eq!(ideal_quantum_entanglement(&packages, 3).unwrap(), 1 << 63);
+        assert_eq!(Day24::part1(&packages).unwrap_err().to_string(), "no answer: the quantum entanglement 9223372036854775808 does not fit in an i64");
+
+        let packages = parse(&[1 << 30, 1 << 32, 1 << 30, 1 << 32, 1 << 30, 1 << 32]);
+        assert_eq!(Day24::part1(&packages).unwrap(), Answer::Number(1 << 62));
+    }
+}
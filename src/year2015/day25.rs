@@ -0,0 +1,144 @@
+use itertools::Itertools;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+
+const FIRST_CODE: u64 = 20151125;
+const MULTIPLIER: u64 = 252533;
+const MODULUS: u64 = 33554393;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = (u64, u64);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let words = input.split_whitespace()
+            .map(|w| w.trim_end_matches([',', '.']))
+            .collect_vec();
+        let number_after = |label: &str| -> Result<u64> {
+            let position = words.iter()
+                .position(|w| *w == label)
+                .ok_or_else(|| Error::parse(input.trim(), format!("expected a {}", label)))?;
+            let number = words.get(position + 1).copied().unwrap_or_default();
+            match number.parse() {
+                Ok(n) if n > 0 => Ok(n),
+                _ => Err(Error::parse(number, format!("expected a positive {} number", label))),
+            }
+        };
+        let (row, column) = (number_after("row")?, number_after("column")?);
+        if diagonal_index(row, column).is_none() {
+            return Err(Error::parse(input.trim(), "the cell is too far down the grid to number"));
+        }
+        Ok((row, column))
+    }
+
+    fn part1((row, column): &Self::Input) -> Result<Answer> {
+        let code = code_at(*row, *column).ok_or_else(|| Error::unsolvable("the cell is not on the grid or too far down it to number"))?;
+        Answer::try_from(code)
+    }
+
+    fn part2(_: &Self::Input) -> Result<Answer> {
+        Ok("Merry Christmas!".into())
+    }
+
+    /// A cell at most `size` rows down and columns across, `size` at least one.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        let size = Error::check_size(size, 1..)? as u64;
+        Ok(Some(format!(
+            "To continue, please consult the code grid in the manual.  Enter the code at row {}, column {}.",
+            rng.random_range(1..=size), rng.random_range(1..=size),
+        )))
+    }
+}
+
+/// Position of a cell in the order the manual fills its grid: diagonal by diagonal,
+/// each from the bottom left to the top right. Rows, columns and the result start at 1.
+/// Returns `None` for row or column 0, or if the position does not fit in a `u64`.
+pub fn diagonal_index(row: u64, column: u64) -> Option<u64> {
+    if row == 0 || column == 0 {
+        return None;
+    }
+    let diagonal = row.checked_add(column)? - 1;
+    let before = if diagonal % 2 == 0 {
+        (diagonal / 2).checked_mul(diagonal - 1)
+    } else {
+        diagonal.checked_mul((diagonal - 1) / 2)
+    };
+    before?.checked_add(column)
+}
+
+pub fn code_at(row: u64, column: u64) -> Option<u64> {
+    Some(FIRST_CODE * mod_pow(MULTIPLIER, diagonal_index(row, column)? - 1, MODULUS)? % MODULUS)
+}
+
+/// Computes `base ^ exponent % modulus`, multiplying in `u128` so that any `u64` modulus works.
+/// Returns `None` for modulus 0.
+pub fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> Option<u64> {
+    if modulus == 0 {
+        return None;
+    }
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1 % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    Some(result as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day25::{code_at, diagonal_index, mod_pow, Day25};
+    use crate::solution::Solution;
+
+    #[test]
+    fn numbers_cells_diagonally() {
+        assert_eq!(diagonal_index(1, 1), Some(1));
+        assert_eq!(diagonal_index(2, 1), Some(2));
+        assert_eq!(diagonal_index(1, 2), Some(3));
+        assert_eq!(diagonal_index(4, 2), Some(12));
+        assert_eq!(diagonal_index(1, 6), Some(21));
+        assert_eq!(diagonal_index(2_000_000_000, 2_000_000_000), Some(7_999_999_996_000_000_001));
+        assert_eq!(diagonal_index(4_000_000_000, 4_000_000_000), None);
+        assert_eq!(diagonal_index(0, 1), None);
+        assert_eq!(diagonal_index(1, 0), None);
+    }
+
+    #[test]
+    fn raises_to_a_power_modulo_large_numbers() {
+        assert_eq!(mod_pow(3, 4, 7), Some(4));
+        assert_eq!(mod_pow(u64::MAX - 1, 2, u64::MAX), Some(1));
+        assert_eq!(mod_pow(1 << 40, 3, (1 << 61) - 1), Some(1 << 59));
+        assert_eq!(mod_pow(3, 4, 0), None);
+    }
+
+    #[test]
+    fn computes_codes_from_the_manual() {
+        assert_eq!(code_at(1, 1), Some(20151125));
+        assert_eq!(code_at(4, 2), Some(32451966));
+        assert_eq!(code_at(6, 6), Some(27995004));
+    }
+
+    #[test]
+    fn parses_row_and_column() {
+        let input = "To continue, please consult the code grid in the manual.  Enter the code at row 2947, column 3029.";
+
+        assert_eq!(Day25::parse(input).unwrap(), (2947, 3029));
+        assert!(Day25::parse("Enter the code at row 0, column 3.").is_err());
+        assert!(Day25::parse("Enter the code.").is_err());
+        assert!(Day25::parse("Enter the code at row 4000000000, column 4000000000.").is_err());
+    }
+
+    #[test]
+    fn reports_cells_off_the_grid() {
+        assert_eq!(Day25::part1(&(0, 5)).unwrap_err().to_string(), "no answer: the cell is not on the grid or too far down it to number");
+        assert!(Day25::part1(&(4_000_000_000, 4_000_000_000)).is_err());
+    }
+}
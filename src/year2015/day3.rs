@@ -0,0 +1,80 @@
+use itertools::Itertools;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let input = input.trim();
+        match input.chars().position(|c| !"<>^v".contains(c)) {
+            Some(i) => Err(Error::parse(input, format!("unexpected direction at position {}", i + 1))),
+            None => Ok(input.to_string()),
+        }
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(number_of_houses_visited(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(number_of_houses_visited_with_robo_santa(input))
+    }
+
+    /// `size` moves.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        Ok(Some((0..size).map(|_| ['^', 'v', '<', '>'][rng.random_range(0..4)]).collect()))
+    }
+}
+
+pub fn number_of_houses_visited(directions: &str) -> usize {
+    let houses = get_houses(directions);
+    houses.iter().unique().count()
+}
+
+fn get_houses(directions: &str) -> Vec<(i32, i32)> {
+    let mut houses = vec![(0, 0)];
+    for c in directions.chars() {
+        let (x, y) = *houses.last().unwrap();
+        houses.push(match c {
+            '<' => (x - 1, y),
+            '>' => (x + 1, y),
+            '^' => (x, y - 1),
+            'v' => (x, y + 1),
+            _ => unreachable!()
+        })
+    }
+    houses
+}
+
+pub fn number_of_houses_visited_with_robo_santa(directions: &str) -> usize {
+    let dirs: String = directions.chars().step_by(2).collect();
+    let robo_dirs: String = directions.chars().skip(1).step_by(2).collect();
+    get_houses(&dirs).iter()
+        .chain(get_houses(&robo_dirs).iter())
+        .unique().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day3::{number_of_houses_visited, number_of_houses_visited_with_robo_santa};
+
+    #[test]
+    fn counts_houses_visited_by_santa() {
+        assert_eq!(number_of_houses_visited(">"), 2);
+        assert_eq!(number_of_houses_visited("^>v<"), 4);
+        assert_eq!(number_of_houses_visited("^v^v^v^v^v"), 2);
+    }
+
+    #[test]
+    fn counts_houses_visited_with_robo_santa() {
+        assert_eq!(number_of_houses_visited_with_robo_santa("^v"), 3);
+        assert_eq!(number_of_houses_visited_with_robo_santa("^>v<"), 3);
+        assert_eq!(number_of_houses_visited_with_robo_santa("^v^v^v^v^v"), 11);
+        assert_eq!(number_of_houses_visited_with_robo_santa("^v^"), 4);
+    }
+}
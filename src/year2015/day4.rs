@@ -0,0 +1,53 @@
+use itertools::{repeat_n};
+use md5::Digest;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(hash(input, 5))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(hash(input, 6))
+    }
+
+    /// A secret key of `size` letters, at least one.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        Ok(Some((0..Error::check_size(size, 1..)?).map(|_| rng.random_range('a'..='z')).collect()))
+    }
+}
+
+pub fn hash(secret_key: &str, number_of_zeroes: usize) -> usize {
+    let pattern: String = repeat_n('0', number_of_zeroes).collect();
+    for i in 1.. {
+        let s = format!("{}{}", secret_key, i);
+        let digest: Digest = md5::compute(s.as_bytes());
+        let encoded_string = format!("{:x}", digest);
+        if encoded_string.starts_with(&pattern) {
+            return i;
+        }
+    }
+    panic!()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day4::hash;
+
+    #[test]
+    fn mines_example_coins() {
+        assert_eq!(hash("abcdef", 5), 609043);
+        assert_eq!(hash("pqrstuv", 5), 1048970);
+    }
+}
@@ -0,0 +1,97 @@
+
+use itertools::{Itertools};
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::error::Result;
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(util::parse_strings(input))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(count_nice_strings(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(count_nice_strings_2(input))
+    }
+
+    /// `size` strings of 16 letters.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        Ok(Some((0..size)
+            .map(|_| (0..16).map(|_| rng.random_range('a'..='z')).collect::<String>())
+            .join("\n")))
+    }
+}
+
+pub fn count_nice_strings(strings: &[String]) -> usize {
+    strings.iter()
+        .filter(|s| is_nice(s))
+        .count()
+}
+
+pub fn count_nice_strings_2(strings: &[String]) -> usize {
+    strings.iter()
+        .filter(|s| is_nice_2(s))
+        .count()
+}
+
+pub fn is_nice(string: &str) -> bool {
+    let has_three_vowels = string.chars()
+        .filter(|s| "aeiou".contains(*s))
+        .count() >= 3;
+    let contains_repeated_letter = string.chars()
+        .tuple_windows::<(char, char)>()
+        .find(|(a, b)| a == b)
+        .is_some();
+    let contains_banned_string = ["ab", "cd", "pq", "xy"].iter()
+        .any(|p| string.contains(p));
+    has_three_vowels && contains_repeated_letter && !contains_banned_string
+}
+
+pub fn is_nice_2(string: &str) -> bool {
+    let contains_sandwich = string.chars()
+        .tuple_windows::<(char, char, char)>()
+        .find(|(a, _, b)| a == b)
+        .is_some();
+
+    let pairs_indexed = string.chars()
+        .tuple_windows::<(char, char)>()
+        .enumerate()
+        .map(|(a, b)| (b, a))
+        .into_group_map();
+
+    let has_separate_pairs = pairs_indexed
+        .values()
+        .find(|indices| {
+            indices.len() > 2 || indices.len() == 2 && indices[0].abs_diff(indices[1]) > 1
+        }).is_some();
+    contains_sandwich && has_separate_pairs
+}
+
+#[cfg(test)]
+mod test {
+    use crate::year2015::day5::{is_nice, is_nice_2};
+
+    #[test]
+    fn nice_and_naughty() {
+        assert!(is_nice("ugknbfddgicrmopn"));
+        assert!(is_nice("aaa"));
+        assert!(!is_nice("jchzalrnumimnmhp"));
+        assert!(!is_nice("haegwjzuvuyypxyu"));
+        assert!(!is_nice("dvszwmarrgswjxmb"));
+    }
+
+    #[test]
+    fn nice_and_naughty_2() {
+        assert!(is_nice_2("qjhvhtzxzqqjkmpb"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,133 @@
+use std::str::FromStr;
+use itertools::{Itertools};
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+
+use crate::year2015::day6::Action::{Toggle, TurnOff, TurnOn};
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        util::parse_from_strings(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(count_lit(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(count_total_brightness(input))
+    }
+
+    /// `size` instructions, at least one.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        Ok(Some((0..Error::check_size(size, 1..)?)
+            .map(|_| {
+                let action = ["turn on", "turn off", "toggle"][rng.random_range(0..3)];
+                let (x, y) = (rng.random_range(0..1000), rng.random_range(0..1000));
+                format!("{} {},{} through {},{}", action, x, y, rng.random_range(x..1000), rng.random_range(y..1000))
+            })
+            .join("\n")))
+    }
+}
+
+pub fn count_lit(instructions: &[Instruction]) -> usize {
+    let mut arr = vec![[false; 1000]; 1000];
+    for instruction in instructions {
+        for row in &mut arr[instruction.start.0..=instruction.end.0] {
+            for light in &mut row[instruction.start.1..=instruction.end.1] {
+                match instruction.action {
+                    TurnOn => { *light = true }
+                    TurnOff => { *light = false }
+                    Toggle => { *light = !*light }
+                }
+            }
+        }
+    }
+    arr.iter()
+        .flatten()
+        .filter(|lit| **lit)
+        .count()
+}
+
+pub fn count_total_brightness(instructions: &[Instruction]) -> usize {
+    let mut arr = vec![[0usize; 1000]; 1000];
+    for instruction in instructions {
+        for row in &mut arr[instruction.start.0..=instruction.end.0] {
+            for light in &mut row[instruction.start.1..=instruction.end.1] {
+                match instruction.action {
+                    TurnOn => { *light += 1 }
+                    TurnOff => { if *light > 0 { *light -= 1 } }
+                    Toggle => { *light += 2 }
+                }
+            }
+        }
+    }
+    arr.iter()
+        .flatten()
+        .sum()
+}
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Debug)]
+pub enum Action {
+    TurnOn,
+    TurnOff,
+    Toggle,
+}
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Debug)]
+pub struct Instruction {
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+    pub action: Action,
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (action, [x1, y1, x2, y2]) = match &s.replace(',', " ").split_whitespace().collect_vec()[..] {
+            ["turn", "on", x1, y1, "through", x2, y2] => (TurnOn, [x1, y1, x2, y2].map(|c| c.parse::<usize>())),
+            ["turn", "off", x1, y1, "through", x2, y2] => (TurnOff, [x1, y1, x2, y2].map(|c| c.parse::<usize>())),
+            ["toggle", x1, y1, "through", x2, y2] => (Toggle, [x1, y1, x2, y2].map(|c| c.parse::<usize>())),
+            _ => return Err(Error::parse(s, "expected `turn on|turn off|toggle x,y through x,y`")),
+        };
+        let instruction = Instruction { start: (x1?, y1?), end: (x2?, y2?), action };
+        if instruction.start.0 > instruction.end.0 || instruction.start.1 > instruction.end.1
+            || instruction.end.0 >= 1000 || instruction.end.1 >= 1000 {
+            return Err(Error::parse(s, "expected a rectangle within the 1000x1000 grid"));
+        }
+        Ok(instruction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day6::{count_lit, count_total_brightness, Day6};
+    use crate::solution::Solution;
+
+    #[test]
+    fn counts_lit_lights() {
+        let instructions = Day6::parse("turn on 0,0 through 999,999\ntoggle 0,0 through 999,0\nturn off 499,499 through 500,500").unwrap();
+
+        assert_eq!(count_lit(&instructions), 1_000_000 - 1000 - 4);
+    }
+
+    #[test]
+    fn counts_total_brightness() {
+        assert_eq!(count_total_brightness(&Day6::parse("turn on 0,0 through 0,0").unwrap()), 1);
+        assert_eq!(count_total_brightness(&Day6::parse("toggle 0,0 through 999,999").unwrap()), 2_000_000);
+    }
+
+    #[test]
+    fn rejects_unknown_actions() {
+        assert!(Day6::parse("flip 0,0 through 1,1").is_err());
+    }
+}
@@ -0,0 +1,689 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hash;
+use std::ops::{BitAnd, BitOr, BitXor};
+use std::str::FromStr;
+use clap::ValueEnum;
+use itertools::{Itertools};
+use petgraph::algo::toposort;
+use petgraph::graph::{DiGraph, NodeIndex};
+use rand::Rng;
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+use crate::year2015::day7::Instruction::{And, Copy, LRotate, LShift, Nand, Nor, Not, Or, RRotate, RShift, Xor};
+use crate::year2015::day7::ValueSource::{Const, Register};
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Circuit;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let circuit: Circuit = input.parse()?;
+        if circuit.signal("a").is_none() || circuit.signal("b").is_none() {
+            return Err(Error::parse("", "expected gates driving the wires `a` and `b`"));
+        }
+        Ok(circuit)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(execute(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(execute_2(input)?.into())
+    }
+
+    /// A circuit of `size` gates in random order, with wire `b` as one of its inputs and wire `a`
+    /// as its output.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        // Names of at least two letters never clash with `a` and `b`
+        let name = |i: usize| {
+            let mut n = i + 26;
+            let mut name = vec![];
+            while n > 0 {
+                name.push((b'a' + (n % 26) as u8) as char);
+                n /= 26;
+            }
+            name.into_iter().rev().collect::<String>()
+        };
+        let mut wires = vec!["b".to_string()];
+        let mut lines = vec![format!("{} -> b", rng.random::<u16>())];
+        for i in 0..size {
+            let to = name(i);
+            let from_1 = wires[rng.random_range(0..wires.len())].clone();
+            let from_2 = wires[rng.random_range(0..wires.len())].clone();
+            lines.push(match rng.random_range(0..7) {
+                0 => format!("{} -> {}", rng.random::<u16>(), to),
+                1 => format!("NOT {} -> {}", from_1, to),
+                2 => format!("{} OR {} -> {}", from_1, from_2, to),
+                3 => format!("{} AND {} -> {}", from_1, from_2, to),
+                4 => format!("1 AND {} -> {}", from_1, to),
+                5 => format!("{} LSHIFT {} -> {}", from_1, rng.random_range(1..16), to),
+                _ => format!("{} RSHIFT {} -> {}", from_1, rng.random_range(1..16), to),
+            });
+            wires.push(to);
+        }
+        lines.push(format!("{} -> a", wires.last().unwrap()));
+        lines.shuffle(rng);
+        Ok(Some(lines.join("\n")))
+    }
+}
+
+/// The signal on wire `a`.
+pub fn execute(circuit: &Circuit) -> Result<u16> {
+    Ok(circuit.signals[circuit.position("a")?])
+}
+
+/// The signal on wire `a` after overriding wire `b` with it.
+pub fn execute_2(circuit: &Circuit) -> Result<u16> {
+    let mut circuit = circuit.clone();
+    circuit.override_wire("b", execute(&circuit)?)?;
+    execute(&circuit)
+}
+
+/// The gates of a circuit and the signals on its wires. The gates are checked to form a DAG,
+/// so that each gate is evaluated exactly once.
+#[derive(Clone, Debug)]
+pub struct Circuit<W = u16> {
+    /// In topological order, so every gate comes after the gates driving its inputs
+    gates: Vec<Instruction<W>>,
+    /// The position in `gates` of the gate driving each wire
+    positions: HashMap<String, usize>,
+    /// The positions of the gates reading the wire driven by each gate
+    consumers: Vec<Vec<usize>>,
+    /// The position in `gates` of each instruction it was built from, in their original order
+    order: Vec<usize>,
+    /// Fixed signals that replace the gates at these positions
+    overrides: HashMap<usize, W>,
+    signals: Vec<W>,
+}
+
+impl<W: Word> Circuit<W> {
+    /// Orders `gates` by their dependencies and evaluates them. Errors name the (1-based) position
+    /// of the offending gate, which is its line in the puzzle input.
+    pub fn new(gates: Vec<Instruction<W>>) -> Result<Circuit<W>> {
+        let mut graph = DiGraph::<usize, ()>::new();
+        let nodes = (0..gates.len()).map(|i| graph.add_node(i)).collect_vec();
+        let mut drivers = HashMap::new();
+        for (i, gate) in gates.iter().enumerate() {
+            for name in gate.inputs().into_iter().chain([gate.output()]) {
+                wire(name).map_err(|e| e.at_line(i + 1, ""))?;
+            }
+            if drivers.insert(gate.output(), i).is_some() {
+                return Err(Error::parse("", format!("wire `{}` is driven by more than one gate", gate.output()))
+                    .at_line(i + 1, ""));
+            }
+        }
+        for (i, gate) in gates.iter().enumerate() {
+            for wire in gate.inputs() {
+                let Some(driver) = drivers.get(wire) else {
+                    return Err(Error::parse("", format!("wire `{}` is not driven by any gate", wire)).at_line(i + 1, ""));
+                };
+                graph.add_edge(nodes[*driver], nodes[i], ());
+            }
+        }
+        let sorted = toposort(&graph, None).map_err(|cycle| {
+            let i = graph[cycle.node_id()];
+            Error::parse("", format!("wire `{}` depends on itself", gates[i].output())).at_line(i + 1, "")
+        })?;
+
+        let mut gates = gates.into_iter().map(Some).collect_vec();
+        let gates = sorted.iter().map(|node| gates[graph[*node]].take().unwrap()).collect_vec();
+        let mut order = vec![0; gates.len()];
+        for (position, node) in sorted.into_iter().enumerate() {
+            order[graph[node]] = position;
+        }
+        let positions: HashMap<String, usize> = gates.iter()
+            .enumerate()
+            .map(|(i, gate)| (gate.output().to_string(), i))
+            .collect();
+        let mut consumers = vec![vec![]; gates.len()];
+        for (i, gate) in gates.iter().enumerate() {
+            for wire in gate.inputs() {
+                consumers[positions[wire]].push(i);
+            }
+        }
+        let mut circuit = Circuit { signals: vec![W::default(); gates.len()], gates, positions, consumers, order, overrides: HashMap::new() };
+        for i in 0..circuit.gates.len() {
+            circuit.signals[i] = circuit.compute(i);
+        }
+        Ok(circuit)
+    }
+
+    /// The gates in the order they are evaluated.
+    pub fn gates(&self) -> &[Instruction<W>] {
+        &self.gates
+    }
+
+    /// The instructions the circuit was built from, in their original order.
+    pub fn instructions(&self) -> Vec<&Instruction<W>> {
+        self.order.iter().map(|i| &self.gates[*i]).collect()
+    }
+
+    fn position(&self, wire: &str) -> Result<usize> {
+        self.positions.get(wire)
+            .copied()
+            .ok_or_else(|| Error::NotFound { kind: "wire", name: wire.to_string() })
+    }
+
+    /// The signal of the gate at position `i`, from the current signals on its inputs.
+    fn compute(&self, i: usize) -> W {
+        if let Some(signal) = self.overrides.get(&i) {
+            return *signal;
+        }
+        let ev = |source: &ValueSource<W>| match source {
+            Const(x) => *x,
+            Register(r) => self.signals[self.positions[r]],
+        };
+        match &self.gates[i] {
+            Copy { from, .. } => ev(from),
+            Not { from, .. } => !ev(from),
+            Or { from_1, from_2, .. } => ev(from_1) | ev(from_2),
+            And { from_1, from_2, .. } => ev(from_1) & ev(from_2),
+            Xor { from_1, from_2, .. } => ev(from_1) ^ ev(from_2),
+            Nand { from_1, from_2, .. } => !(ev(from_1) & ev(from_2)),
+            Nor { from_1, from_2, .. } => !(ev(from_1) | ev(from_2)),
+            LShift { from, value, .. } => ev(from).shift_left(ev(value)),
+            RShift { from, value, .. } => ev(from).shift_right(ev(value)),
+            LRotate { from, value, .. } => ev(from).rotate_left(ev(value)),
+            RRotate { from, value, .. } => ev(from).rotate_right(ev(value)),
+        }
+    }
+
+    pub fn signal(&self, wire: &str) -> Option<W> {
+        self.positions.get(wire).map(|i| self.signals[*i])
+    }
+
+    /// The signal on every wire.
+    pub fn signals(&self) -> HashMap<String, W> {
+        self.positions.iter()
+            .map(|(wire, i)| (wire.clone(), self.signals[*i]))
+            .collect()
+    }
+
+    /// Fixes the signal on `wire` to `value`, ignoring the gate that drives it, and recomputes the
+    /// wires downstream of it whose inputs changed. Returns the number of gates it evaluated.
+    pub fn override_wire(&mut self, wire: &str, value: W) -> Result<usize> {
+        let start = self.position(wire)?;
+        self.overrides.insert(start, value);
+        // Positions are in topological order, so popping the smallest evaluates every gate after its inputs
+        let mut pending = BTreeSet::from([start]);
+        let mut evaluated = 0;
+        while let Some(i) = pending.pop_first() {
+            let signal = self.compute(i);
+            evaluated += 1;
+            if signal != self.signals[i] {
+                self.signals[i] = signal;
+                pending.extend(&self.consumers[i]);
+            }
+        }
+        Ok(evaluated)
+    }
+
+    /// The wires as nodes labelled with the gate driving them, and an edge labelled with the
+    /// operation from every wire to the gates reading it.
+    pub fn graph(&self) -> DiGraph<String, &'static str> {
+        let mut graph = DiGraph::new();
+        let nodes = self.gates.iter().map(|gate| graph.add_node(gate.to_string())).collect::<Vec<NodeIndex>>();
+        for (i, gate) in self.gates.iter().enumerate() {
+            for wire in gate.inputs() {
+                graph.add_edge(nodes[self.positions[wire]], nodes[i], gate.operation());
+            }
+        }
+        graph
+    }
+
+    /// Source code of a function that computes every wire with straight-line code, one gate after
+    /// another in topological order. Overridden wires are compiled to their fixed signal. The
+    /// function returns the signals in the order of the `WIRES` constant next to it. Wire names
+    /// only contain lowercase letters, so they can be used in identifiers and string literals as is.
+    pub fn compile(&self, language: Language) -> String {
+        let name = |wire: &str| format!("w_{}", wire);
+        let (word, constant): (String, fn(W) -> String) = match language {
+            Language::Rust => (format!("u{}", W::BITS), |x| x.to_string()),
+            Language::C => (format!("uint{}_t", W::BITS), |x| format!("{}u", x)),
+        };
+        let source = |source: &ValueSource<W>| match source {
+            Const(x) => constant(*x),
+            Register(r) => name(r),
+        };
+        let expressions = self.gates.iter().enumerate().map(|(i, gate)| {
+            if let Some(signal) = self.overrides.get(&i) {
+                return constant(*signal);
+            }
+            let call = |function: &str, from: &ValueSource<W>, value: &ValueSource<W>| {
+                format!("{}({}, {})", function, source(from), source(value))
+            };
+            let (not, cast) = match language {
+                Language::Rust => ("!", String::new()),
+                Language::C => ("~", format!("({})", word)),
+            };
+            match gate {
+                Copy { from, .. } => source(from),
+                Not { from, .. } => format!("{}{}{}", cast, not, source(from)),
+                Or { from_1, from_2, .. } => format!("{} | {}", source(from_1), source(from_2)),
+                And { from_1, from_2, .. } => format!("{} & {}", source(from_1), source(from_2)),
+                Xor { from_1, from_2, .. } => format!("{} ^ {}", source(from_1), source(from_2)),
+                Nand { from_1, from_2, .. } => format!("{}{}({} & {})", cast, not, source(from_1), source(from_2)),
+                Nor { from_1, from_2, .. } => format!("{}{}({} | {})", cast, not, source(from_1), source(from_2)),
+                LShift { from, value, .. } => call("shl", from, value),
+                RShift { from, value, .. } => call("shr", from, value),
+                LRotate { from, value, .. } => call("rotl", from, value),
+                RRotate { from, value, .. } => call("rotr", from, value),
+            }
+        });
+        let wires = self.gates.iter().map(|gate| gate.output()).collect_vec();
+        let bits = W::BITS;
+        let n = wires.len();
+        let uses = |operation: &str| self.gates.iter()
+            .enumerate()
+            .any(|(i, gate)| gate.operation() == operation && !self.overrides.contains_key(&i));
+        let helpers = ["LSHIFT", "RSHIFT", "LROTATE", "RROTATE"].map(uses);
+
+        let mut code = String::new();
+        match language {
+            Language::Rust => {
+                code += &format!("pub const WIRES: [&str; {}] = [{}];\n\n", n, wires.iter().map(|w| format!("{:?}", w)).join(", "));
+                if helpers[0] {
+                    code += &format!("fn shl(x: {w}, n: {w}) -> {w} {{\n    if n < {b} {{ x << n }} else {{ 0 }}\n}}\n\n", w = word, b = bits);
+                }
+                if helpers[1] {
+                    code += &format!("fn shr(x: {w}, n: {w}) -> {w} {{\n    if n < {b} {{ x >> n }} else {{ 0 }}\n}}\n\n", w = word, b = bits);
+                }
+                if helpers[2] {
+                    code += &format!("fn rotl(x: {w}, n: {w}) -> {w} {{\n    x.rotate_left((n % {b}) as u32)\n}}\n\n", w = word, b = bits);
+                }
+                if helpers[3] {
+                    code += &format!("fn rotr(x: {w}, n: {w}) -> {w} {{\n    x.rotate_right((n % {b}) as u32)\n}}\n\n", w = word, b = bits);
+                }
+                code += &format!("pub fn circuit() -> [{}; {}] {{\n", word, n);
+                for (wire, expression) in wires.iter().zip(expressions) {
+                    code += &format!("    let {}: {} = {};\n", name(wire), word, expression);
+                }
+                code += &format!("    [{}]\n}}\n", wires.iter().map(|w| name(w)).join(", "));
+            }
+            Language::C => {
+                code += "#include <stdint.h>\n\n";
+                // C has no empty arrays or initializers, so an empty circuit gets one unused slot
+                let names = if wires.is_empty() { "0".to_string() } else { wires.iter().map(|w| format!("\"{}\"", w)).join(", ") };
+                code += &format!("const char *const WIRES[{}] = {{{}}};\n\n", n.max(1), names);
+                if helpers[0] {
+                    code += &format!("static {w} shl({w} x, {w} n) {{\n    return n < {b} ? ({w})(x << n) : 0;\n}}\n\n", w = word, b = bits);
+                }
+                if helpers[1] {
+                    code += &format!("static {w} shr({w} x, {w} n) {{\n    return n < {b} ? ({w})(x >> n) : 0;\n}}\n\n", w = word, b = bits);
+                }
+                if helpers[2] {
+                    code += &format!("static {w} rotl({w} x, {w} n) {{\n    n %= {b};\n    return n == 0 ? x : ({w})((x << n) | (x >> ({b} - n)));\n}}\n\n", w = word, b = bits);
+                }
+                if helpers[3] {
+                    code += &format!("static {w} rotr({w} x, {w} n) {{\n    n %= {b};\n    return n == 0 ? x : ({w})((x >> n) | (x << ({b} - n)));\n}}\n\n", w = word, b = bits);
+                }
+                code += &format!("void circuit({} wires[{}]) {{\n", word, n.max(1));
+                for (wire, expression) in wires.iter().zip(expressions) {
+                    code += &format!("    const {} {} = {};\n", word, name(wire), expression);
+                }
+                for (i, wire) in wires.iter().enumerate() {
+                    code += &format!("    wires[{}] = {};\n", i, name(wire));
+                }
+                code += "}\n";
+            }
+        }
+        code
+    }
+
+    /// The wires that the signal on `wire` depends on, directly or through other gates.
+    /// Overridden wires do not depend on anything.
+    pub fn dependencies(&self, wire: &str) -> Result<BTreeSet<&str>> {
+        let mut dependencies = BTreeSet::new();
+        let mut pending = vec![self.position(wire)?];
+        while let Some(i) = pending.pop() {
+            if self.overrides.contains_key(&i) {
+                continue;
+            }
+            for input in self.gates[i].inputs() {
+                if dependencies.insert(input) {
+                    pending.push(self.positions[input]);
+                }
+            }
+        }
+        Ok(dependencies)
+    }
+}
+
+impl<W: Word> TryFrom<Vec<Instruction<W>>> for Circuit<W> {
+    type Error = Error;
+
+    fn try_from(instructions: Vec<Instruction<W>>) -> Result<Self> {
+        Circuit::new(instructions)
+    }
+}
+
+impl<W: Word> FromStr for Circuit<W> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Circuit::new(util::parse_from_strings(s)?)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum Language {
+    /// A Rust function returning an array
+    Rust,
+    /// A C function filling an array, using `stdint.h`
+    C,
+}
+
+/// An unsigned integer type that the wires of a circuit carry.
+pub trait Word: std::marker::Copy + Default + Eq + Hash + Ord + Debug + Display + FromStr
+    + std::ops::Not<Output = Self> + BitAnd<Output = Self> + BitOr<Output = Self> + BitXor<Output = Self> {
+    const BITS: u32;
+
+    /// Shifts by `n` bits, giving 0 when all bits are shifted out.
+    fn shift_left(self, n: Self) -> Self;
+    fn shift_right(self, n: Self) -> Self;
+    /// Rotates by `n` modulo the number of bits.
+    fn rotate_left(self, n: Self) -> Self;
+    fn rotate_right(self, n: Self) -> Self;
+}
+
+macro_rules! words {
+    ($($word:ty),*) => {
+        $(
+            impl Word for $word {
+                const BITS: u32 = <$word>::BITS;
+
+                fn shift_left(self, n: Self) -> Self {
+                    if n < Self::BITS as Self { self << n } else { 0 }
+                }
+
+                fn shift_right(self, n: Self) -> Self {
+                    if n < Self::BITS as Self { self >> n } else { 0 }
+                }
+
+                fn rotate_left(self, n: Self) -> Self {
+                    <$word>::rotate_left(self, (n % Self::BITS as Self) as u32)
+                }
+
+                fn rotate_right(self, n: Self) -> Self {
+                    <$word>::rotate_right(self, (n % Self::BITS as Self) as u32)
+                }
+            }
+        )*
+    };
+}
+
+words!(u8, u16, u32, u64);
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Clone)]
+pub enum ValueSource<W = u16> {
+    Const(W),
+    Register(String),
+}
+
+impl<W: Word> FromStr for ValueSource<W> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if !s.starts_with(|c: char| c.is_ascii_digit()) {
+            return Ok(Register(wire(s)?));
+        }
+        s.parse()
+            .map(Const)
+            .map_err(|_| Error::parse(s, format!("expected a constant of at most {} bits", W::BITS)))
+    }
+}
+
+/// Checks that `s` is a wire name, which is one or more lowercase letters. This keeps the names
+/// safe to use in the identifiers and string literals of compiled circuits.
+fn wire(s: &str) -> Result<String> {
+    if s.is_empty() || !s.bytes().all(|c| c.is_ascii_lowercase()) {
+        return Err(Error::parse(s, "expected a wire name of lowercase letters"));
+    }
+    Ok(s.to_string())
+}
+
+impl<W: Word> Display for ValueSource<W> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Const(x) => write!(f, "{}", x),
+            Register(r) => write!(f, "{}", r),
+        }
+    }
+}
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Clone)]
+pub enum Instruction<W = u16> {
+    Copy { from: ValueSource<W>, to: String },
+    Not { from: ValueSource<W>, to: String },
+    Or { from_1: ValueSource<W>, from_2: ValueSource<W>, to: String },
+    And { from_1: ValueSource<W>, from_2: ValueSource<W>, to: String },
+    Xor { from_1: ValueSource<W>, from_2: ValueSource<W>, to: String },
+    Nand { from_1: ValueSource<W>, from_2: ValueSource<W>, to: String },
+    Nor { from_1: ValueSource<W>, from_2: ValueSource<W>, to: String },
+    LShift { from: ValueSource<W>, value: ValueSource<W>, to: String },
+    RShift { from: ValueSource<W>, value: ValueSource<W>, to: String },
+    LRotate { from: ValueSource<W>, value: ValueSource<W>, to: String },
+    RRotate { from: ValueSource<W>, value: ValueSource<W>, to: String },
+}
+
+impl<W> Instruction<W> {
+    /// The wire this gate drives.
+    pub fn output(&self) -> &str {
+        match self {
+            Copy { to, .. } | Not { to, .. } | Or { to, .. } | And { to, .. } | Xor { to, .. } | Nand { to, .. }
+            | Nor { to, .. } | LShift { to, .. } | RShift { to, .. } | LRotate { to, .. } | RRotate { to, .. } => to,
+        }
+    }
+
+    pub fn operation(&self) -> &'static str {
+        match self {
+            Copy { .. } => "COPY",
+            Not { .. } => "NOT",
+            Or { .. } => "OR",
+            And { .. } => "AND",
+            Xor { .. } => "XOR",
+            Nand { .. } => "NAND",
+            Nor { .. } => "NOR",
+            LShift { .. } => "LSHIFT",
+            RShift { .. } => "RSHIFT",
+            LRotate { .. } => "LROTATE",
+            RRotate { .. } => "RROTATE",
+        }
+    }
+
+    /// The inputs of this gate, in the order the puzzle input writes them.
+    pub fn sources(&self) -> Vec<&ValueSource<W>> {
+        match self {
+            Copy { from, .. } | Not { from, .. } => vec![from],
+            Or { from_1, from_2, .. } | And { from_1, from_2, .. } | Xor { from_1, from_2, .. }
+            | Nand { from_1, from_2, .. } | Nor { from_1, from_2, .. } => vec![from_1, from_2],
+            LShift { from, value, .. } | RShift { from, value, .. }
+            | LRotate { from, value, .. } | RRotate { from, value, .. } => vec![from, value],
+        }
+    }
+
+    /// The wires this gate reads.
+    pub fn inputs(&self) -> Vec<&str> {
+        self.sources().into_iter()
+            .filter_map(|source| match source {
+                Register(r) => Some(r.as_str()),
+                Const(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// Formats the gate the way the puzzle input writes it.
+impl<W: Word> Display for Instruction<W> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (self, &self.sources()[..]) {
+            (Copy { .. }, [from]) => write!(f, "{} -> {}", from, self.output()),
+            (Not { .. }, [from]) => write!(f, "NOT {} -> {}", from, self.output()),
+            (_, [a, b]) => write!(f, "{} {} {} -> {}", a, self.operation(), b, self.output()),
+            _ => unreachable!("Gates have one or two inputs"),
+        }
+    }
+}
+
+impl<W: Word> FromStr for Instruction<W> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match &s.split_whitespace().collect_vec()[..] {
+            [value, "->", t] => Copy { from: value.parse()?, to: wire(t)? },
+            ["NOT", from, "->", t] => Not { from: from.parse()?, to: wire(t)? },
+            [a, "OR", b, "->", t] => Or { from_1: a.parse()?, from_2: b.parse()?, to: wire(t)? },
+            [a, "AND", b, "->", t] => And { from_1: a.parse()?, from_2: b.parse()?, to: wire(t)? },
+            [a, "XOR", b, "->", t] => Xor { from_1: a.parse()?, from_2: b.parse()?, to: wire(t)? },
+            [a, "NAND", b, "->", t] => Nand { from_1: a.parse()?, from_2: b.parse()?, to: wire(t)? },
+            [a, "NOR", b, "->", t] => Nor { from_1: a.parse()?, from_2: b.parse()?, to: wire(t)? },
+            [from, "LSHIFT", value, "->", t] => LShift { from: from.parse()?, value: value.parse()?, to: wire(t)? },
+            [from, "RSHIFT", value, "->", t] => RShift { from: from.parse()?, value: value.parse()?, to: wire(t)? },
+            [from, "LROTATE", value, "->", t] => LRotate { from: from.parse()?, value: value.parse()?, to: wire(t)? },
+            [from, "RROTATE", value, "->", t] => RRotate { from: from.parse()?, value: value.parse()?, to: wire(t)? },
+            _ => return Err(Error::parse(s, "unknown instruction")),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day7::{execute, execute_2, Circuit, Language};
+    use crate::year2015::day7::Instruction::Copy;
+    use crate::year2015::day7::ValueSource::Const;
+
+    #[test]
+    fn simulates_example_circuit() {
+        let circuit: Circuit = "123 -> x\n456 -> y\nx AND y -> d\nx OR y -> e\nx LSHIFT 2 -> f\ny RSHIFT 2 -> g\nNOT x -> h\nNOT y -> i".parse().unwrap();
+
+        let expected = [("d", 72), ("e", 507), ("f", 492), ("g", 114), ("h", 65412), ("i", 65079), ("x", 123), ("y", 456)];
+        assert_eq!(circuit.signals(), expected.into_iter().map(|(w, v)| (w.to_string(), v)).collect());
+    }
+
+    #[test]
+    fn evaluates_gates_in_dependency_order() {
+        let circuit: Circuit = "x AND y -> d\nNOT d -> a\n123 -> x\n456 -> y".parse().unwrap();
+
+        assert_eq!(circuit.gates().iter().map(|g| g.output()).collect::<Vec<_>>()[2..], ["d", "a"]);
+        assert_eq!(circuit.instructions().iter().map(|g| g.output()).collect::<Vec<_>>(), ["d", "a", "x", "y"]);
+        assert_eq!(circuit.signal("a"), Some(!72));
+    }
+
+    #[test]
+    fn rejects_invalid_circuits() {
+        let error = |input: &str| input.parse::<Circuit>().unwrap_err().to_string();
+
+        assert_eq!(error("1 -> x\nx AND y -> a"), "line 2: wire `y` is not driven by any gate");
+        assert_eq!(error("1 -> x\nx OR c -> b\nNOT b -> c"), "line 3: wire `c` depends on itself");
+        assert_eq!(error("1 -> x\n2 -> x"), "line 2: wire `x` is driven by more than one gate");
+    }
+
+    #[test]
+    fn recomputes_downstream_of_overrides() {
+        let mut circuit: Circuit = "3 -> b\n5 -> c\nb AND c -> d\nNOT c -> e\nd OR e -> a".parse().unwrap();
+
+        assert_eq!(circuit.override_wire("b", 4).unwrap(), 3);
+        assert_eq!(circuit.signal("d"), Some(4));
+        assert_eq!(circuit.signal("a"), Some(!5 | 4));
+        // 6 AND 5 is still 4, so nothing past `d` is evaluated again
+        assert_eq!(circuit.override_wire("b", 6).unwrap(), 2);
+        assert_eq!(circuit.signal("a"), Some(!5 | 4));
+        assert_eq!(circuit.override_wire("x", 1).unwrap_err().to_string(), "unknown wire `x`");
+    }
+
+    #[test]
+    fn reports_missing_wires_a_and_b() {
+        let circuit: Circuit = "1 -> x\nNOT x -> a".parse().unwrap();
+
+        assert_eq!(execute(&circuit).unwrap(), !1);
+        assert_eq!(execute_2(&circuit).unwrap_err().to_string(), "unknown wire `b`");
+        assert_eq!(execute(&"1 -> x".parse().unwrap()).unwrap_err().to_string(), "unknown wire `a`");
+    }
+
+    #[test]
+    fn finds_dependencies() {
+        let mut circuit: Circuit = "3 -> b\n5 -> c\nb AND c -> d\nNOT c -> e\nd OR e -> a".parse().unwrap();
+
+        assert_eq!(circuit.dependencies("a").unwrap().into_iter().collect::<Vec<_>>(), ["b", "c", "d", "e"]);
+        assert_eq!(circuit.dependencies("e").unwrap().into_iter().collect::<Vec<_>>(), ["c"]);
+        circuit.override_wire("d", 0).unwrap();
+        assert_eq!(circuit.dependencies("a").unwrap().into_iter().collect::<Vec<_>>(), ["c", "d", "e"]);
+    }
+
+    #[test]
+    fn builds_wire_graph() {
+        let circuit: Circuit = "123 -> x\nNOT x -> y\nx AND y -> a".parse().unwrap();
+
+        let graph = circuit.graph();
+
+        assert_eq!(graph.node_weights().collect::<Vec<_>>(), ["123 -> x", "NOT x -> y", "x AND y -> a"]);
+        assert_eq!(graph.edge_weights().copied().collect::<Vec<_>>(), ["NOT", "AND", "AND"]);
+    }
+
+    #[test]
+    fn simulates_other_word_widths_and_gates() {
+        let input = "200 -> x\n7 -> y\nx XOR y -> a\nx NAND y -> b\nx NOR y -> c\nx LROTATE 3 -> d\nx RROTATE 3 -> e\nx LSHIFT 9 -> f";
+        let narrow: Circuit<u8> = input.parse().unwrap();
+        let wide: Circuit<u64> = input.parse().unwrap();
+
+        assert_eq!([&"a", &"b", &"c", &"d", &"e", &"f"].map(|w| narrow.signal(w).unwrap()), [207, 255, 48, 70, 25, 0]);
+        assert_eq!(wide.signal("d"), Some(1600));
+        assert_eq!(wide.signal("e"), Some(25));
+        assert_eq!(wide.signal("f"), Some(102400));
+        assert_eq!("300 -> x".parse::<Circuit<u8>>().unwrap_err().to_string(), "line 1: expected a constant of at most 8 bits\n    | 300");
+    }
+
+    #[test]
+    fn rejects_wire_names_other_than_lowercase_letters() {
+        let error = |input: &str| input.parse::<Circuit>().unwrap_err().to_string();
+
+        assert_eq!(error("1 -> x\nx AND 1 -> x-y"), "line 2: expected a wire name of lowercase letters\n    | x-y");
+        assert_eq!(error("NOT \"];evil();// -> a"), "line 1: expected a wire name of lowercase letters\n    | \"];evil();//");
+        let hostile = vec![Copy { from: Const(1), to: "x\"; }".to_string() }];
+        assert_eq!(Circuit::<u16>::new(hostile).unwrap_err().to_string(), "line 1: expected a wire name of lowercase letters\n    | x\"; }");
+    }
+
+    #[test]
+    fn compiles_to_straight_line_code() {
+        let mut circuit: Circuit = "x RSHIFT 2 -> a\n123 -> x\nNOT x -> y\ny LSHIFT 1 -> b".parse().unwrap();
+        circuit.override_wire("b", 7).unwrap();
+
+        assert_eq!(circuit.compile(Language::Rust), "\
+pub const WIRES: [&str; 4] = [\"x\", \"y\", \"b\", \"a\"];
+
+fn shr(x: u16, n: u16) -> u16 {
+    if n < 16 { x >> n } else { 0 }
+}
+
+pub fn circuit() -> [u16; 4] {
+    let w_x: u16 = 123;
+    let w_y: u16 = !w_x;
+    let w_b: u16 = 7;
+    let w_a: u16 = shr(w_x, 2);
+    [w_x, w_y, w_b, w_a]
+}
+");
+        assert!(circuit.compile(Language::C).contains("    const uint16_t w_y = (uint16_t)~w_x;\n"));
+    }
+
+    #[test]
+    fn compiles_an_empty_circuit_to_valid_c() {
+        let circuit = Circuit::<u16>::new(vec![]).unwrap();
+
+        assert_eq!(circuit.compile(Language::C), "\
+#include <stdint.h>
+
+const char *const WIRES[1] = {0};
+
+void circuit(uint16_t wires[1]) {
+}
+");
+    }
+}
@@ -0,0 +1,122 @@
+use std::str::FromStr;
+use itertools::Itertools;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<Literal>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        util::parse_from_strings(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(count_unescapes(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Answer::try_from(count_escapes(input))
+    }
+
+    /// `size` string literals, at least one.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        Ok(Some((0..Error::check_size(size, 1..)?)
+            .map(|_| {
+                let content: String = (0..rng.random_range(0..20))
+                    .map(|_| match rng.random_range(0..10) {
+                        0 => "\\\\".to_string(),
+                        1 => "\\\"".to_string(),
+                        2 => format!("\\x{:02x}", rng.random::<u8>()),
+                        _ => rng.random_range('a'..='z').to_string(),
+                    })
+                    .collect();
+                format!("\"{}\"", content)
+            })
+            .join("\n")))
+    }
+}
+
+pub fn count_unescapes(literals: &[Literal]) -> usize {
+    let escaped_len: usize = literals.iter().map(|l| l.escaped.chars().count()).sum();
+    let unescaped_len: usize = literals.iter().map(|l| l.unescaped.chars().count()).sum();
+    escaped_len - unescaped_len
+}
+
+pub fn unescape(string: &str) -> Result<String> {
+    let mut it = string
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .ok_or_else(|| Error::parse(string, "expected a string literal in double quotes"))?
+        .chars();
+    let mut acc: Vec<char> = vec![];
+    while let Some(c) = it.next() {
+        match c {
+            '\\' => match it.next() {
+                Some('x') => {
+                    let (x1, x2) = match (it.next(), it.next()) {
+                        (Some(x1), Some(x2)) => (x1.to_digit(16), x2.to_digit(16)),
+                        _ => (None, None),
+                    };
+                    let (Some(x1), Some(x2)) = (x1, x2) else {
+                        return Err(Error::parse(string, "expected two hex digits after `\\x`"));
+                    };
+                    acc.push(char::from_u32(16 * x1 + x2).unwrap())
+                }
+                Some(c @ ('\\' | '"')) => acc.push(c),
+                _ => return Err(Error::parse(string, "unknown escape sequence")),
+            }
+            c => acc.push(c)
+        }
+    }
+    Ok(acc.iter().collect())
+}
+
+pub fn count_escapes(literals: &[Literal]) -> usize {
+     literals.iter()
+        .map(|l| l.escaped.chars().filter(|c| *c == '\\' || *c == '\"').count() + 2)
+        .sum()
+}
+
+pub struct Literal {
+    pub escaped: String,
+    pub unescaped: String,
+}
+
+impl FromStr for Literal {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Literal { escaped: s.to_string(), unescaped: unescape(s)? })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day8::{count_escapes, count_unescapes, unescape, Day8};
+    use crate::solution::Solution;
+
+    const EXAMPLE: &str = r#"""
+"abc"
+"aaa\"aaa"
+"\x27""#;
+
+    #[test]
+    fn counts_example_characters() {
+        let literals = Day8::parse(EXAMPLE).unwrap();
+
+        assert_eq!(count_unescapes(&literals), 12);
+        assert_eq!(count_escapes(&literals), 19);
+    }
+
+    #[test]
+    fn unescapes_literals() {
+        assert_eq!(unescape(r#""aaa\"aaa""#).unwrap(), "aaa\"aaa");
+        assert_eq!(unescape(r#""\x27""#).unwrap(), "'");
+        assert!(unescape(r#""\q""#).is_err());
+    }
+}
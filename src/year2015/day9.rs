@@ -0,0 +1,168 @@
+use std::collections::{HashMap};
+use std::str::FromStr;
+use itertools::{Itertools, MinMaxResult};
+use petgraph::graph::UnGraph;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Connection>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        util::parse_from_strings(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(routes(input)?.0.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(routes(input)?.1.into())
+    }
+
+    /// The distances between all of `size` cities, at least two.
+    fn generate(size: usize, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        Ok(Some((0..Error::check_size(size, 2..)?)
+            .tuple_combinations()
+            .map(|(a, b)| format!("City{} to City{} = {}", a, b, rng.random_range(1..=200)))
+            .join("\n")))
+    }
+}
+
+fn routes(connections: &[Connection]) -> Result<(u32, u32)> {
+    if connections.is_empty() {
+        return Err(Error::unsolvable("there are no cities to visit"));
+    }
+    shortest_roundtrip(connections)?
+        .into_option()
+        .ok_or_else(|| Error::unsolvable("no route has a known distance between every pair of stops"))
+}
+
+/// The shortest and longest route through all cities, over routes with a known distance between
+/// every pair of stops. Fails if the length of a route does not fit in a `u32`.
+pub fn shortest_roundtrip(connections: &[Connection]) -> Result<MinMaxResult<u32>> {
+    let lookup: HashMap<(String, String), u32> = connections
+        .iter()
+        .flat_map(|Connection { a, b, cost }| [
+            ((a.clone(), b.clone()), *cost),
+            ((b.clone(), a.clone()), *cost)
+        ])
+        .collect();
+    let locations = connections
+        .iter()
+        .flat_map(|Connection { a, b, .. }| [a, b])
+        .unique()
+        .cloned()
+        .collect_vec();
+    if locations.is_empty() {
+        return Ok(MinMaxResult::NoElements);
+    }
+    itertools::process_results(
+        locations
+            .iter()
+            .permutations(locations.len())
+            .map(|perm| route_length(&lookup, perm)),
+        |lengths| lengths.flatten().minmax(),
+    )
+}
+
+/// The length of the route through `cities` in order, if every stop is a known distance from the
+/// one before.
+fn route_length(lookup: &HashMap<(String, String), u32>, cities: Vec<&String>) -> Result<Option<u32>> {
+    let mut length = 0u32;
+    for pair in cities.into_iter().cloned().tuple_windows::<(String, String)>() {
+        let Some(cost) = lookup.get(&pair) else {
+            return Ok(None);
+        };
+        length = length
+            .checked_add(*cost)
+            .ok_or_else(|| Error::unsolvable("the length of a route does not fit in a u32"))?;
+    }
+    Ok(Some(length))
+}
+
+/// The cities as nodes, connected by edges weighted with their distance.
+pub fn graph(connections: &[Connection]) -> UnGraph<String, u32> {
+    let mut graph = UnGraph::new_undirected();
+    let mut nodes = HashMap::new();
+    for Connection { a, b, cost } in connections {
+        let a = *nodes.entry(a).or_insert_with(|| graph.add_node(a.clone()));
+        let b = *nodes.entry(b).or_insert_with(|| graph.add_node(b.clone()));
+        graph.add_edge(a, b, *cost);
+    }
+    graph
+}
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Debug)]
+pub struct Connection {
+    pub a: String,
+    pub b: String,
+    pub cost: u32,
+}
+
+impl FromStr for Connection {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if let [a, "to", b, "=", cost] = s.split_whitespace().collect_vec()[..] {
+            return Ok(Connection {
+                a: a.to_string(),
+                b: b.to_string(),
+                cost: cost.parse()?,
+            })
+        }
+        Err(Error::parse(s, "expected `<city> to <city> = <distance>`"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2015::day9::{graph, shortest_roundtrip, Day9};
+    use crate::solution::Solution;
+
+    #[test]
+    fn finds_example_routes() {
+        let connections = Day9::parse("London to Dublin = 464\nLondon to Belfast = 518\nDublin to Belfast = 141").unwrap();
+
+        assert_eq!(shortest_roundtrip(&connections).unwrap().into_option(), Some((605, 982)));
+    }
+
+    #[test]
+    fn reports_routes_without_cities() {
+        assert_eq!(Day9::part1(&Day9::parse("").unwrap()).unwrap_err().to_string(), "no answer: there are no cities to visit");
+    }
+
+    #[test]
+    fn skips_routes_with_unknown_distances() {
+        let connections = Day9::parse("A to B = 1\nB to C = 2\nA to D = 5").unwrap();
+
+        assert_eq!(shortest_roundtrip(&connections).unwrap().into_option(), Some((8, 8)));
+        assert_eq!(
+            Day9::part1(&Day9::parse("A to B = 1\nC to D = 2").unwrap()).unwrap_err().to_string(),
+            "no answer: no route has a known distance between every pair of stops",
+        );
+    }
+
+    #[test]
+    fn reports_routes_that_overflow() {
+        let connections = Day9::parse("A to B = 4294967295\nB to C = 4294967295").unwrap();
+
+        assert_eq!(Day9::part1(&connections).unwrap_err().to_string(), "no answer: the length of a route does not fit in a u32");
+    }
+
+    #[test]
+    fn builds_city_graph() {
+        let connections = Day9::parse("London to Dublin = 464\nLondon to Belfast = 518\nDublin to Belfast = 141").unwrap();
+
+        let graph = graph(&connections);
+
+        assert_eq!(graph.node_weights().collect::<Vec<_>>(), ["London", "Dublin", "Belfast"]);
+        assert_eq!(graph.edge_weights().sum::<u32>(), 464 + 518 + 141);
+    }
+}
@@ -0,0 +1,115 @@
+use std::ops::RangeInclusive;
+
+use itertools::Itertools;
+use advent_of_code::day14::{self, Reindeer};
+use advent_of_code::day7::{Circuit, Day7, Instruction, ValueSource};
+use advent_of_code::solution::{Answer, Day, Part, Solution};
+use advent_of_code::{year2015, DAYS, YEARS};
+
+#[test]
+fn solves_through_the_solution_trait() {
+    let circuit = Day7::parse("b -> a\n123 -> b").unwrap();
+
+    assert_eq!(circuit.gates()[0], Instruction::Copy { from: ValueSource::Const(123), to: "b".to_string() });
+    assert_eq!(Day7::part1(&circuit).unwrap(), Answer::Number(123));
+}
+
+#[test]
+fn builds_circuits_from_parsed_instructions() {
+    let instructions = vec![
+        Instruction::Not { from: ValueSource::Register("b".to_string()), to: "a".to_string() },
+        Instruction::Copy { from: ValueSource::Const(1), to: "b".to_string() },
+    ];
+    let circuit: Circuit = instructions.clone().try_into().unwrap();
+
+    assert_eq!(circuit.instructions(), instructions.iter().collect::<Vec<_>>());
+    assert_eq!(circuit.signal("a"), Some(!1));
+}
+
+#[test]
+fn calls_part_functions_directly() {
+    let comet = Reindeer { speed: 14, endurance: 10, rest_time: 127 };
+    let dancer = Reindeer { speed: 16, endurance: 11, rest_time: 162 };
+
+    assert_eq!(comet.distance_after(1000), 1120);
+    assert_eq!(day14::winning_reindeer_after(&[comet, dancer], 1000), 1120);
+}
+
+#[test]
+fn registers_every_day() {
+    assert_eq!(DAYS.iter().map(|d| d.day).collect::<Vec<_>>(), (1..=25).collect::<Vec<_>>());
+
+    assert_eq!(YEARS.iter().map(|y| y.year).collect::<Vec<_>>(), vec![2015]);
+    assert_eq!(YEARS[0].days.len(), year2015::DAYS.len());
+
+    let day1 = YEARS[0].day(1).unwrap();
+    let run = (day1.solve)("(()(()(", &[Part::One]).unwrap();
+    assert_eq!(run.parts[0].answer, Answer::Number(3));
+}
+
+#[test]
+fn solves_generated_inputs() {
+    for day in DAYS {
+        // From the smallest size each day can generate, kept small for the days that try every
+        // arrangement or whose search grows quickly with the size.
+        let sizes = match day.day {
+            1 | 3 | 5 | 7 => 0..=6,
+            9 | 13 => 2..=6,
+            12 | 16 => 2..=8,
+            11 => 5..=11,
+            15 => 1..=3,
+            17 => 4..=10,
+            21 => 50..=56,
+            23 | 24 => 12..=18,
+            _ => 1..=7,
+        };
+        solve_generated(day, sizes);
+    }
+}
+
+/// The sizes left out of `solves_generated_inputs`, as days 9 and 13 take seconds per input from 7.
+#[test]
+#[ignore]
+fn solves_large_generated_inputs() {
+    for day in DAYS.iter().filter(|d| d.day == 9 || d.day == 13) {
+        solve_generated(day, 7..=8);
+    }
+}
+
+fn solve_generated(day: &Day, sizes: RangeInclusive<usize>) {
+    for (size, seed) in sizes.clone().cartesian_product(0..5) {
+        let input = (day.generate)(size, seed).unwrap().unwrap_or_else(|| panic!("day {} has no generator", day.day));
+        assert_eq!(input, (day.generate)(size, seed).unwrap().unwrap(), "day {} is not deterministic", day.day);
+
+        // The hash search of day 4 takes millions of steps whatever the input, so only parse it.
+        // Day 10 grows any input to millions of digits, and the second password of day 11 is hundreds
+        // of thousands of increments past any generated one, so only solve them once.
+        let solve_once = day.day == 10 || day.day == 11;
+        let parse_only = day.day == 4 || (solve_once && (size, seed) != (*sizes.start(), 0));
+        let parts = if parse_only { &[][..] } else { &Part::ALL[..] };
+        let run = (day.solve)(&input, parts).unwrap_or_else(|e| panic!("day {} size {} seed {}: {}", day.day, size, seed, e));
+        if day.day == 19 {
+            assert_eq!(run.parts[1].answer, Answer::Number(size as i64));
+        }
+    }
+}
+
+#[test]
+fn generates_inputs_of_the_requested_size() {
+    let day9 = YEARS[0].day(9).unwrap();
+    assert_eq!((day9.generate)(50, 0).unwrap().unwrap().lines().count(), 50 * 49 / 2);
+
+    let day23 = YEARS[0].day(23).unwrap();
+    assert_eq!((day23.generate)(40, 0).unwrap().unwrap().lines().count(), 40);
+
+    let day24 = YEARS[0].day(24).unwrap();
+    assert_eq!((day24.generate)(1440, 0).unwrap().unwrap().lines().count(), 1440);
+
+    assert_eq!(
+        (day24.generate)(1441, 0).unwrap_err().to_string(),
+        "cannot generate an input of size 1441, expected between 12 and 1440",
+    );
+    assert!((day9.generate)(1, 0).is_err());
+    assert!((YEARS[0].day(12).unwrap().generate)(1, 0).is_err());
+    assert!((YEARS[0].day(22).unwrap().generate)(71, 0).is_err());
+}
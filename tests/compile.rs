@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use advent_of_code::day7::{Circuit, Language, Word};
+
+const GATES: &str = "\
+123 -> x
+201 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i
+x XOR y -> j
+x NAND y -> k
+x NOR y -> l
+x LROTATE 3 -> m
+y RROTATE 13 -> n
+y LSHIFT 70 -> o
+x RROTATE 64 -> p
+g LROTATE x -> q
+m RSHIFT i -> r
+q OR r -> if
+if XOR x -> fn
+fn AND y -> int
+NOT int -> do";
+
+/// Runs `program` and reads back the `wire value` lines it prints.
+fn run(program: &Path) -> HashMap<String, String> {
+    let output = Command::new(program).output().expect("Failed to run the compiled circuit");
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+        .lines()
+        .map(|line| {
+            let (wire, value) = line.split_once(' ').unwrap();
+            (wire.to_string(), value.to_string())
+        })
+        .collect()
+}
+
+/// The compiler named by the environment variable `var`, or `default`, if it can be started.
+/// Otherwise says why the compiled code is not checked, so that `cargo test` needs no C toolchain.
+fn compiler(var: &str, default: &str) -> Option<String> {
+    let command = std::env::var(var).unwrap_or_else(|_| default.to_string());
+    match Command::new(&command).arg("--version").output() {
+        Ok(output) if output.status.success() => Some(command),
+        _ => {
+            eprintln!("skipping the check of compiled code: `{}` is not available, set {} to use another compiler", command, var);
+            None
+        }
+    }
+}
+
+fn build(command: &str, args: &[&str], source: &Path, program: &Path) {
+    let status = Command::new(command)
+        .args(args)
+        .arg("-o").arg(program)
+        .arg(source)
+        .status()
+        .unwrap_or_else(|e| panic!("Failed to start `{}`: {}", command, e));
+    assert!(status.success(), "`{}` could not compile {}", command, source.display());
+}
+
+fn compiled_rust<W: Word>(circuit: &Circuit<W>) -> Option<HashMap<String, String>> {
+    let rustc = compiler("RUSTC", "rustc")?;
+    let dir = tempfile::tempdir().expect("Failed to create directory");
+    let (source, program) = (dir.path().join("circuit.rs"), dir.path().join("circuit"));
+    let main = "fn main() {\n    for (wire, signal) in WIRES.iter().zip(circuit()) {\n        println!(\"{} {}\", wire, signal);\n    }\n}\n";
+    fs::write(&source, circuit.compile(Language::Rust) + main).unwrap();
+    build(&rustc, &["--edition", "2021", "-O"], &source, &program);
+    Some(run(&program))
+}
+
+fn compiled_c<W: Word>(circuit: &Circuit<W>) -> Option<HashMap<String, String>> {
+    let cc = compiler("CC", "cc")?;
+    let dir = tempfile::tempdir().expect("Failed to create directory");
+    let (source, program) = (dir.path().join("circuit.c"), dir.path().join("circuit"));
+    let n = circuit.gates().len();
+    let main = format!(
+        "#include <stdio.h>\n\nint main(void) {{\n    uint{0}_t wires[{1}];\n    circuit(wires);\n    for (int i = 0; i < {2}; i++) {{\n        printf(\"%s %llu\\n\", WIRES[i], (unsigned long long)wires[i]);\n    }}\n    return 0;\n}}\n",
+        W::BITS, n.max(1), n,
+    );
+    fs::write(&source, circuit.compile(Language::C) + &main).unwrap();
+    build(&cc, &["-std=c99", "-pedantic-errors", "-Wall", "-O2"], &source, &program);
+    Some(run(&program))
+}
+
+fn assert_compiles_to_interpreter<W: Word>(circuit: &Circuit<W>) {
+    let signals = circuit.signals().into_iter()
+        .map(|(wire, signal)| (wire, signal.to_string()))
+        .collect::<HashMap<_, _>>();
+
+    if let Some(compiled) = compiled_rust(circuit) {
+        assert_eq!(compiled, signals);
+    }
+    if let Some(compiled) = compiled_c(circuit) {
+        assert_eq!(compiled, signals);
+    }
+}
+
+#[test]
+fn compiles_every_gate_like_the_interpreter() {
+    assert_compiles_to_interpreter(&GATES.parse::<Circuit<u8>>().unwrap());
+    assert_compiles_to_interpreter(&GATES.parse::<Circuit<u16>>().unwrap());
+    assert_compiles_to_interpreter(&GATES.parse::<Circuit<u32>>().unwrap());
+    assert_compiles_to_interpreter(&GATES.parse::<Circuit<u64>>().unwrap());
+}
+
+#[test]
+fn compiles_an_empty_circuit() {
+    assert_compiles_to_interpreter(&Circuit::<u16>::new(vec![]).unwrap());
+}
+
+#[test]
+fn compiles_the_puzzle_input_like_the_interpreter() {
+    let mut circuit = fs::read_to_string("resources/2015/day7.txt").unwrap().parse::<Circuit>().unwrap();
+    assert_compiles_to_interpreter(&circuit);
+
+    let a = circuit.signal("a").unwrap();
+    circuit.override_wire("b", a).unwrap();
+    assert_compiles_to_interpreter(&circuit);
+}
@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+use proptest::prelude::*;
+use proptest::sample::subsequence;
+
+use advent_of_code::solution::{Part, Solution};
+use advent_of_code::year2015::{day14, day16, day19, day2, day21, day23, day6, day7, day8};
+use advent_of_code::year2015::DAYS;
+
+fn wire() -> impl Strategy<Value = String> {
+    "[a-z]{1,3}"
+}
+
+fn value_source() -> impl Strategy<Value = day7::ValueSource> {
+    prop_oneof![
+        any::<u16>().prop_map(day7::ValueSource::Const),
+        wire().prop_map(day7::ValueSource::Register),
+    ]
+}
+
+fn gate() -> impl Strategy<Value = day7::Instruction> {
+    use day7::Instruction::*;
+    prop_oneof![
+        (value_source(), wire()).prop_map(|(from, to)| Copy { from, to }),
+        (value_source(), wire()).prop_map(|(from, to)| Not { from, to }),
+        (value_source(), value_source(), wire()).prop_map(|(from_1, from_2, to)| Or { from_1, from_2, to }),
+        (value_source(), value_source(), wire()).prop_map(|(from_1, from_2, to)| And { from_1, from_2, to }),
+        (value_source(), value_source(), wire()).prop_map(|(from, value, to)| LShift { from, value, to }),
+        (value_source(), value_source(), wire()).prop_map(|(from, value, to)| RShift { from, value, to }),
+        (value_source(), value_source(), wire()).prop_map(|(from_1, from_2, to)| Xor { from_1, from_2, to }),
+        (value_source(), value_source(), wire()).prop_map(|(from_1, from_2, to)| Nand { from_1, from_2, to }),
+        (value_source(), value_source(), wire()).prop_map(|(from_1, from_2, to)| Nor { from_1, from_2, to }),
+        (value_source(), value_source(), wire()).prop_map(|(from, value, to)| LRotate { from, value, to }),
+        (value_source(), value_source(), wire()).prop_map(|(from, value, to)| RRotate { from, value, to }),
+    ]
+}
+
+fn register() -> impl Strategy<Value = day23::Register> {
+    prop_oneof![Just(day23::Register::A), Just(day23::Register::B)]
+}
+
+fn program_line() -> impl Strategy<Value = day23::Instruction> {
+    use day23::Instruction::*;
+    prop_oneof![
+        register().prop_map(Half),
+        register().prop_map(Triple),
+        register().prop_map(Increment),
+        any::<i32>().prop_map(|offset| Jump(offset as i64)),
+        (register(), any::<i32>()).prop_map(|(r, offset)| JumpIfEven(r, offset as i64)),
+        (register(), any::<i32>()).prop_map(|(r, offset)| JumpIfOne(r, offset as i64)),
+    ]
+}
+
+fn register_name(register: day23::Register) -> &'static str {
+    match register {
+        day23::Register::A => "a",
+        day23::Register::B => "b",
+    }
+}
+
+/// Escapes `s` the way the string literals of day 8 are written.
+fn escape(s: &str) -> String {
+    let escaped: String = s.chars()
+        .map(|c| match c {
+            '\\' | '"' => format!("\\{}", c),
+            ' '..='~' => c.to_string(),
+            _ => format!("\\x{:02x}", c as u32),
+        })
+        .collect();
+    format!("\"{}\"", escaped)
+}
+
+/// How long a day may take to solve an input before the test fails.
+const SOLVE_BUDGET: Duration = Duration::from_secs(2);
+
+/// Sizes of generated inputs, often small enough to also solve them.
+fn sizes() -> impl Strategy<Value = usize> {
+    prop_oneof![0..20usize, 0..200usize]
+}
+
+/// Whether to also solve a generated input of `size`, not just parse it. Days 4, 10 and 11 are
+/// only parsed, as some inputs take them millions of steps. The others are kept small where they
+/// try every arrangement or their search grows quickly with the size.
+fn solvable(day: u32, size: usize) -> bool {
+    let max_size = match day {
+        4 | 10 | 11 => return false,
+        9 | 13 => 7,
+        15 => 3,
+        17 => 12,
+        21 => 60,
+        24 => 18,
+        _ => 20,
+    };
+    size <= max_size
+}
+
+/// Solves both parts of `input` with the day at `day` in `DAYS`, failing if it panics or takes
+/// longer than `SOLVE_BUDGET`. Errors are fine, malformed and unsolvable inputs are expected to have
+/// them. Inputs too expensive to solve in time should be left out by `solvable` instead.
+fn solve_without_panicking(day: usize, input: String) -> Result<(), TestCaseError> {
+    let (sender, receiver) = mpsc::channel();
+    let solver = thread::spawn(move || {
+        let _ = (DAYS[day].solve)(&input, &Part::ALL);
+        let _ = sender.send(());
+    });
+    match receiver.recv_timeout(SOLVE_BUDGET) {
+        Ok(()) | Err(RecvTimeoutError::Disconnected) => match solver.join() {
+            Ok(()) => Ok(()),
+            Err(_) => Err(TestCaseError::fail(format!("day {} panicked", DAYS[day].day))),
+        },
+        Err(RecvTimeoutError::Timeout) => Err(TestCaseError::fail(format!("day {} took longer than {:?}", DAYS[day].day, SOLVE_BUDGET))),
+    }
+}
+
+proptest! {
+    #[test]
+    fn parses_generated_inputs(day in 0..DAYS.len(), size in sizes(), seed: u64) {
+        // Sizes the day's format cannot have are rejected by the generator
+        let Ok(input) = (DAYS[day].generate)(size, seed) else {
+            return Ok(());
+        };
+        let input = input.unwrap();
+
+        prop_assert!((DAYS[day].solve)(&input, &[]).is_ok());
+        if solvable(DAYS[day].day, size) {
+            solve_without_panicking(day, input)?;
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_input_without_panicking(day in 0..DAYS.len(), size in sizes(), seed: u64, at: usize, cut in 0..6usize, insert in "[ a-z0-9,:x>+\\-\\\\\"\n]{0,4}") {
+        let Ok(input) = (DAYS[day].generate)(size, seed) else {
+            return Ok(());
+        };
+        let mut input = input.unwrap();
+        let at = at % (input.len() + 1);
+        let end = (at + cut).min(input.len());
+        prop_assume!(input.is_char_boundary(at) && input.is_char_boundary(end));
+        input.replace_range(at..end, &insert);
+
+        let _ = (DAYS[day].solve)(&input, &[]);
+        if solvable(DAYS[day].day, size) {
+            solve_without_panicking(day, input)?;
+        }
+    }
+
+    #[test]
+    fn rejects_arbitrary_input_without_panicking(day in 0..DAYS.len(), input in "\\PC{0,40}") {
+        let _ = (DAYS[day].solve)(&input, &[]);
+    }
+
+    #[test]
+    fn round_trips_packages(x: u32, y: u32, z: u32) {
+        let package = format!("{}x{}x{}", x, y, z).parse::<day2::Package>().unwrap();
+
+        prop_assert_eq!((package.x, package.y, package.z), (x, y, z));
+    }
+
+    #[test]
+    fn round_trips_light_instructions(action in 0..3usize, x in 0..1000usize, y in 0..1000usize, width in 0..1000usize, height in 0..1000usize) {
+        let end = ((x + width).min(999), (y + height).min(999));
+        let (name, action) = [("turn on", day6::Action::TurnOn), ("turn off", day6::Action::TurnOff), ("toggle", day6::Action::Toggle)]
+            .into_iter()
+            .nth(action)
+            .unwrap();
+        let line = format!("{} {},{} through {},{}", name, x, y, end.0, end.1);
+
+        prop_assert_eq!(line.parse::<day6::Instruction>().unwrap(), day6::Instruction { start: (x, y), end, action });
+    }
+
+    #[test]
+    fn round_trips_gates(gate in gate()) {
+        prop_assert_eq!(gate.to_string().parse::<day7::Instruction>().unwrap(), gate);
+    }
+
+    #[test]
+    fn round_trips_string_literals(s in "[\\x00-\\xff]{0,20}") {
+        prop_assert_eq!(day8::unescape(&escape(&s)).unwrap(), s);
+    }
+
+    #[test]
+    fn round_trips_reindeer(speed: u16, endurance: u16, rest_time in 1..u16::MAX) {
+        let line = format!("Comet can fly {} km/s for {} seconds, but then must rest for {} seconds.", speed, endurance, rest_time);
+        let reindeer = day14::Reindeer { speed: speed as u32, endurance: endurance as u32, rest_time: rest_time as u32 };
+
+        prop_assert_eq!(line.parse::<day14::Reindeer>().unwrap(), reindeer);
+    }
+
+    #[test]
+    fn round_trips_sues(id: u32, compounds in subsequence(day16::ticker_tape().compounds.into_keys().collect::<Vec<_>>(), 0..=3), values: [u32; 3]) {
+        let compounds = compounds.into_iter().zip(values).collect::<HashMap<_, _>>();
+        let line = format!("Sue {}: {}", id, compounds.iter().map(|(c, n)| format!("{}: {}", c, n)).collect::<Vec<_>>().join(", "));
+        let sue = line.parse::<day16::Sue>().unwrap();
+
+        prop_assert_eq!(sue.id, id);
+        prop_assert_eq!(sue.compounds, compounds);
+    }
+
+    #[test]
+    fn round_trips_molecules(atoms in prop::collection::vec("[A-Z][a-z]?", 1..10), molecule in prop::collection::vec("[A-Z][a-z]?", 1..10)) {
+        let replacements = atoms.iter().map(|atom| format!("e => {}", atom)).collect::<Vec<_>>().join("\n");
+        let (transformations, parsed) = day19::Day19::parse(&format!("{}\n\n{}", replacements, molecule.concat())).unwrap();
+
+        prop_assert_eq!(transformations.into_iter().map(|(_, atoms)| atoms.concat()).collect::<Vec<_>>(), atoms);
+        prop_assert_eq!(parsed, molecule);
+    }
+
+    #[test]
+    fn round_trips_characters(hit_points in 1..=i32::MAX, damage in 0..=i32::MAX, armor in 0..=i32::MAX) {
+        let character = format!("Hit Points: {}\nDamage: {}\nArmor: {}", hit_points, damage, armor).parse::<day21::Character>().unwrap();
+
+        prop_assert_eq!(character, day21::Character { hit_points, damage, armor });
+    }
+
+    #[test]
+    fn round_trips_programs(instruction in program_line()) {
+        use day23::Instruction::*;
+        let line = match instruction {
+            Half(r) => format!("hlf {}", register_name(r)),
+            Triple(r) => format!("tpl {}", register_name(r)),
+            Increment(r) => format!("inc {}", register_name(r)),
+            Jump(offset) => format!("jmp {:+}", offset),
+            JumpIfEven(r, offset) => format!("jie {}, {:+}", register_name(r), offset),
+            JumpIfOne(r, offset) => format!("jio {}, {:+}", register_name(r), offset),
+        };
+
+        prop_assert_eq!(line.parse::<day23::Instruction>().unwrap(), instruction);
+    }
+}